@@ -1,29 +1,45 @@
 use std::sync::Arc;
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse};
-use derive_more::Constructor;
+use axum::{extract::{Query, State}, http::StatusCode, response::IntoResponse};
 use prometheus_client::{encoding::text::encode, metrics::gauge, registry::Registry};
-use tokio::try_join;
+use tokio::join;
 
 use crate::{
-    application::server::Controller,
+    application::{
+        metrics::scrape::{scrape, ScrapeCollector, Scraper},
+        server::Controller,
+    },
     service::{
-        bgp::BGPStatusResult,
-        ddns::DdnsStatusResult,
-        ipsec::IPsecResult,
+        bgp::BGPTableResult,
+        conntrack::ConntrackResult,
+        ddns::{DdnsRecordResult, DdnsStatusResult},
+        dhcp::{DhcpLeaseResult, DhcpStatisticsResult},
+        firewall::{FirewallResult, NatResult},
+        interface::InterfaceStatisticsResult,
+        ipsec::{IPsecBackendResult, IPsecConnResult, IPsecCounterResult, IPsecEventResult, IPsecResult, IPsecStatsResult},
         load_balance::LoadBalanceStatusResult,
+        ospf::{OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult},
         pppoe::PPPoEClientSessionResult,
+        system::SystemResult,
         version::VersionResult,
         Runner,
+        COLLECTORS,
     },
 };
 
 mod atomic;
 mod bgp;
+mod conntrack;
 mod ddns;
+mod dhcp;
+mod firewall;
+mod interface;
 mod ipsec;
 mod load_balance;
+mod ospf;
 mod pppoe;
+pub mod scrape;
+mod system;
 mod version;
 
 pub type Gauge<T = i64, U = atomic::AtomicI64> = gauge::Gauge<T, U>;
@@ -32,50 +48,148 @@ pub trait Collector {
     fn collect(self, registry: &mut Registry);
 }
 
-#[derive(Constructor)]
-pub struct MetricsHandler<BGPRunner, DdnsRunner, IPsecRunner, LoadBalanceRunner, PPPoERunner, VersionRunner> {
-    bgp_runner: BGPRunner,
-    ddns_runner: DdnsRunner,
-    ipsec_runner: IPsecRunner,
-    load_balance_runner: LoadBalanceRunner,
-    pppoe_runner: PPPoERunner,
-    version_runner: VersionRunner,
+pub struct MetricsHandler<BGPRunner, ConntrackRunner, DdnsRunner, DhcpRunner, FirewallRunner, InterfaceRunner, IPsecRunner, LoadBalanceRunner, OSPFRunner, PPPoERunner, SystemRunner, VersionRunner>
+where
+    BGPRunner: Runner,
+    ConntrackRunner: Runner,
+    DdnsRunner: Runner,
+    DhcpRunner: Runner,
+    FirewallRunner: Runner,
+    InterfaceRunner: Runner,
+    IPsecRunner: Runner,
+    LoadBalanceRunner: Runner,
+    OSPFRunner: Runner,
+    PPPoERunner: Runner,
+    SystemRunner: Runner,
+    VersionRunner: Runner,
+{
+    bgp: Option<Scraper<BGPRunner>>,
+    conntrack: Option<Scraper<ConntrackRunner>>,
+    ddns: Option<Scraper<DdnsRunner>>,
+    dhcp: Option<Scraper<DhcpRunner>>,
+    firewall: Option<Scraper<FirewallRunner>>,
+    interface: Option<Scraper<InterfaceRunner>>,
+    ipsec: Option<Scraper<IPsecRunner>>,
+    load_balance: Option<Scraper<LoadBalanceRunner>>,
+    ospf: Option<Scraper<OSPFRunner>>,
+    pppoe: Option<Scraper<PPPoERunner>>,
+    system: Option<Scraper<SystemRunner>>,
+    version: Option<Scraper<VersionRunner>>,
+}
+
+impl<BGPRunner, ConntrackRunner, DdnsRunner, DhcpRunner, FirewallRunner, InterfaceRunner, IPsecRunner, LoadBalanceRunner, OSPFRunner, PPPoERunner, SystemRunner, VersionRunner>
+    MetricsHandler<BGPRunner, ConntrackRunner, DdnsRunner, DhcpRunner, FirewallRunner, InterfaceRunner, IPsecRunner, LoadBalanceRunner, OSPFRunner, PPPoERunner, SystemRunner, VersionRunner>
+where
+    BGPRunner: Runner,
+    ConntrackRunner: Runner,
+    DdnsRunner: Runner,
+    DhcpRunner: Runner,
+    FirewallRunner: Runner,
+    InterfaceRunner: Runner,
+    IPsecRunner: Runner,
+    LoadBalanceRunner: Runner,
+    OSPFRunner: Runner,
+    PPPoERunner: Runner,
+    SystemRunner: Runner,
+    VersionRunner: Runner,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        bgp: Option<Scraper<BGPRunner>>,
+        conntrack: Option<Scraper<ConntrackRunner>>,
+        ddns: Option<Scraper<DdnsRunner>>,
+        dhcp: Option<Scraper<DhcpRunner>>,
+        firewall: Option<Scraper<FirewallRunner>>,
+        interface: Option<Scraper<InterfaceRunner>>,
+        ipsec: Option<Scraper<IPsecRunner>>,
+        load_balance: Option<Scraper<LoadBalanceRunner>>,
+        ospf: Option<Scraper<OSPFRunner>>,
+        pppoe: Option<Scraper<PPPoERunner>>,
+        system: Option<Scraper<SystemRunner>>,
+        version: Option<Scraper<VersionRunner>>,
+    ) -> Self {
+        Self {
+            bgp,
+            conntrack,
+            ddns,
+            dhcp,
+            firewall,
+            interface,
+            ipsec,
+            load_balance,
+            ospf,
+            pppoe,
+            system,
+            version,
+        }
+    }
 }
 
-impl<BGPRunner, DdnsRunner, IPsecRunner, LoadBalanceRunner, PPPoERunner, VersionRunner> Controller<String>
-    for MetricsHandler<BGPRunner, DdnsRunner, IPsecRunner, LoadBalanceRunner, PPPoERunner, VersionRunner>
+impl<BGPRunner, ConntrackRunner, DdnsRunner, DhcpRunner, FirewallRunner, InterfaceRunner, IPsecRunner, LoadBalanceRunner, OSPFRunner, PPPoERunner, SystemRunner, VersionRunner> Controller<String>
+    for MetricsHandler<BGPRunner, ConntrackRunner, DdnsRunner, DhcpRunner, FirewallRunner, InterfaceRunner, IPsecRunner, LoadBalanceRunner, OSPFRunner, PPPoERunner, SystemRunner, VersionRunner>
 where
-    BGPRunner: Runner<Item = (BGPStatusResult, BGPStatusResult)> + Send + Sync + 'static,
-    DdnsRunner: Runner<Item = DdnsStatusResult> + Send + Sync + 'static,
-    IPsecRunner: Runner<Item = IPsecResult> + Send + Sync + 'static,
+    BGPRunner: Runner<Item = BGPTableResult> + Send + Sync + 'static,
+    ConntrackRunner: Runner<Item = ConntrackResult> + Send + Sync + 'static,
+    DdnsRunner: Runner<Item = (DdnsStatusResult, Option<DdnsRecordResult>)> + Send + Sync + 'static,
+    DhcpRunner: Runner<Item = (DhcpStatisticsResult, Option<DhcpLeaseResult>)> + Send + Sync + 'static,
+    FirewallRunner: Runner<Item = (FirewallResult, NatResult)> + Send + Sync + 'static,
+    InterfaceRunner: Runner<Item = InterfaceStatisticsResult> + Send + Sync + 'static,
+    IPsecRunner: Runner<Item = (IPsecResult, IPsecConnResult, IPsecStatsResult, IPsecCounterResult, IPsecEventResult, IPsecBackendResult)> + Send + Sync + 'static,
     LoadBalanceRunner: Runner<Item = LoadBalanceStatusResult> + Send + Sync + 'static,
+    OSPFRunner: Runner<Item = (OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult, OSPFNeighborResult)> + Send + Sync + 'static,
     PPPoERunner: Runner<Item = PPPoEClientSessionResult> + Send + Sync + 'static,
+    SystemRunner: Runner<Item = SystemResult> + Send + Sync + 'static,
     VersionRunner: Runner<Item = VersionResult> + Send + Sync + 'static,
 {
-    async fn handle(&self) -> anyhow::Result<String> {
+    async fn handle(&self, query: &[(String, String)]) -> anyhow::Result<String> {
+        let collectors: Vec<_> = query
+            .iter()
+            .filter(|(key, _)| key == "collect[]")
+            .map(|(_, value)| value.as_str())
+            .collect();
+
         let mut registry = Registry::default();
         let (
             bgp,
+            conntrack,
             ddns,
+            dhcp,
+            firewall,
+            interfaces,
             ipsec_sas,
             load_balance_groups,
+            ospf,
             pppoe_client_sessions,
+            system,
             version,
-        ) = try_join!(
-            self.bgp_runner.run(),
-            self.ddns_runner.run(),
-            self.ipsec_runner.run(),
-            self.load_balance_runner.run(),
-            self.pppoe_runner.run(),
-            self.version_runner.run(),
-        )?;
-
-        bgp.collect(&mut registry);
-        ddns.collect(&mut registry);
-        ipsec_sas.collect(&mut registry);
-        load_balance_groups.collect(&mut registry);
-        pppoe_client_sessions.collect(&mut registry);
-        version.collect(&mut registry);
+        ) = join!(
+            scrape(self.bgp.as_ref(), &collectors),
+            scrape(self.conntrack.as_ref(), &collectors),
+            scrape(self.ddns.as_ref(), &collectors),
+            scrape(self.dhcp.as_ref(), &collectors),
+            scrape(self.firewall.as_ref(), &collectors),
+            scrape(self.interface.as_ref(), &collectors),
+            scrape(self.ipsec.as_ref(), &collectors),
+            scrape(self.load_balance.as_ref(), &collectors),
+            scrape(self.ospf.as_ref(), &collectors),
+            scrape(self.pppoe.as_ref(), &collectors),
+            scrape(self.system.as_ref(), &collectors),
+            scrape(self.version.as_ref(), &collectors),
+        );
+
+        let scrape = ScrapeCollector::new(&mut registry);
+        scrape.collect(bgp, &mut registry);
+        scrape.collect(conntrack, &mut registry);
+        scrape.collect(ddns, &mut registry);
+        scrape.collect(dhcp, &mut registry);
+        scrape.collect(firewall, &mut registry);
+        scrape.collect(interfaces, &mut registry);
+        scrape.collect(ipsec_sas, &mut registry);
+        scrape.collect(load_balance_groups, &mut registry);
+        scrape.collect(ospf, &mut registry);
+        scrape.collect(pppoe_client_sessions, &mut registry);
+        scrape.collect(system, &mut registry);
+        scrape.collect(version, &mut registry);
 
         let mut buf = String::new();
         encode(&mut buf, &registry)?;
@@ -84,11 +198,15 @@ where
     }
 }
 
-pub async fn handle<T>(State(controller): State<Arc<T>>) -> impl IntoResponse
+pub async fn handle<T>(State(controller): State<Arc<T>>, Query(query): Query<Vec<(String, String)>>) -> impl IntoResponse
 where
     T: Controller<String>,
 {
-    match controller.handle().await {
+    if let Some((_, collector)) = query.iter().find(|(key, value)| key == "collect[]" && !COLLECTORS.contains(&value.as_str())) {
+        return (StatusCode::BAD_REQUEST, format!("unknown collector: {collector}\n"));
+    }
+
+    match controller.handle(&query).await {
         Ok(s) => {
             (StatusCode::OK, s)
         },
@@ -98,3 +216,41 @@ where
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    struct MetricsController;
+
+    impl Controller<String> for MetricsController {
+        async fn handle(&self, _query: &[(String, String)]) -> anyhow::Result<String> {
+            Ok(String::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn collect() {
+        let query = vec![
+            ("collect[]".to_string(), "bgp".to_string()),
+            ("collect[]".to_string(), "load_balance".to_string()),
+        ];
+
+        let response = handle(State(Arc::new(MetricsController)), Query(query)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn collect_unknown() {
+        let query = vec![
+            ("collect[]".to_string(), "bgp".to_string()),
+            ("collect[]".to_string(), "dns".to_string()),
+        ];
+
+        let response = handle(State(Arc::new(MetricsController)), Query(query)).await.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -6,31 +6,151 @@ use prometheus_client::{
 
 use crate::{
     application::metrics::{Collector, Gauge},
-    domain::bgp::{BGPIterator, BGPNeighbor},
-    service::bgp::BGPStatusResult,
+    domain::bgp::{BGPMessageCounters, BGPNeighbor, BGPNeighborDetail, BGPTable},
+    service::bgp::BGPTableResult,
 };
 
 #[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
 pub struct BGPNeighborLabel {
+    vrf: String,
+    afi: String,
+    safi: String,
     neighbor: String,
     r#as: String,
-    table_version: String,
 }
 
-impl From<BGPNeighbor> for BGPNeighborLabel {
-    fn from(n: BGPNeighbor) -> Self {
-        let neighbor = n.neighbor.to_string();
-        let r#as = n.remote_as.to_string();
-        let table_version = n.table_version.to_string();
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct BGPNeighborStateLabel {
+    vrf: String,
+    afi: String,
+    safi: String,
+    neighbor: String,
+    r#as: String,
+    state: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct BGPSessionLabel {
+    vrf: String,
+    neighbor: String,
+    r#as: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct BGPSessionInfoLabel {
+    vrf: String,
+    neighbor: String,
+    r#as: String,
+    description: String,
+    last_reset_reason: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct BGPSessionMessageLabel {
+    vrf: String,
+    neighbor: String,
+    r#as: String,
+    r#type: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct BGPSessionAddressFamilyLabel {
+    vrf: String,
+    neighbor: String,
+    r#as: String,
+    address_family: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct BGPRouterLabel {
+    vrf: String,
+    afi: String,
+    safi: String,
+    router_id: String,
+    local_as: String,
+}
+
+const BGP_STATES: [&str; 6] = ["Idle", "Connect", "Active", "OpenSent", "OpenConfirm", "Established"];
+
+impl BGPRouterLabel {
+    pub fn new(t: &BGPTable) -> Self {
+        let vrf = t.vrf.clone();
+        let afi = t.afi.clone();
+        let safi = t.safi.clone();
+        let router_id = t.status.router_id.clone();
+        let local_as = t.status.local_as.to_string();
         Self {
-            neighbor,
-            r#as,
-            table_version,
+            vrf,
+            afi,
+            safi,
+            router_id,
+            local_as,
+        }
+    }
+
+    pub fn neighbor(&self, n: &BGPNeighbor) -> BGPNeighborLabel {
+        BGPNeighborLabel {
+            vrf: self.vrf.clone(),
+            afi: self.afi.clone(),
+            safi: self.safi.clone(),
+            neighbor: n.neighbor.to_string(),
+            r#as: n.remote_as.to_string(),
+        }
+    }
+}
+
+impl BGPNeighborLabel {
+    pub fn state(self, state: &str) -> BGPNeighborStateLabel {
+        BGPNeighborStateLabel {
+            vrf: self.vrf,
+            afi: self.afi,
+            safi: self.safi,
+            neighbor: self.neighbor,
+            r#as: self.r#as,
+            state: state.to_string(),
+        }
+    }
+
+    pub fn session(self) -> BGPSessionLabel {
+        BGPSessionLabel {
+            vrf: self.vrf,
+            neighbor: self.neighbor,
+            r#as: self.r#as,
         }
     }
 }
 
-impl Collector for (BGPStatusResult, BGPStatusResult) {
+impl BGPSessionLabel {
+    pub fn info(self, detail: &BGPNeighborDetail) -> BGPSessionInfoLabel {
+        BGPSessionInfoLabel {
+            vrf: self.vrf,
+            neighbor: self.neighbor,
+            r#as: self.r#as,
+            description: detail.description.clone().unwrap_or_default(),
+            last_reset_reason: detail.last_reset_reason.clone().unwrap_or_default(),
+        }
+    }
+
+    pub fn message(self, r#type: &str) -> BGPSessionMessageLabel {
+        BGPSessionMessageLabel {
+            vrf: self.vrf,
+            neighbor: self.neighbor,
+            r#as: self.r#as,
+            r#type: r#type.to_string(),
+        }
+    }
+
+    pub fn address_family(self, address_family: &str) -> BGPSessionAddressFamilyLabel {
+        BGPSessionAddressFamilyLabel {
+            vrf: self.vrf,
+            neighbor: self.neighbor,
+            r#as: self.r#as,
+            address_family: address_family.to_string(),
+        }
+    }
+}
+
+impl Collector for BGPTableResult {
     fn collect(self, registry: &mut Registry) {
         let bgp_msg_rcv = Family::<BGPNeighborLabel, Gauge>::default();
         registry.register(
@@ -74,47 +194,276 @@ impl Collector for (BGPStatusResult, BGPStatusResult) {
             bgp_pfx_rcd.clone(),
         );
 
-        for neighbor in BGPIterator::from(self) {
-            let (
-                messages_received,
-                messages_sent,
-                in_queue,
-                out_queue,
-                uptime,
-                prefixes_received,
-            ) = (
-                neighbor.messages_received,
-                neighbor.messages_sent,
-                neighbor.in_queue,
-                neighbor.out_queue,
-                neighbor.uptime.map(|d| d.as_secs()).unwrap_or_default(),
-                neighbor.prefixes_received.unwrap_or_default(),
-            );
-            let labels = neighbor.into();
-
-            bgp_msg_rcv
-                .get_or_create(&labels)
-                .set(messages_received as i64);
+        let bgp_neighbor_up = Family::<BGPNeighborLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_neighbor_up",
+            "Whether BGP session is established",
+            bgp_neighbor_up.clone(),
+        );
 
-            bgp_msg_sen
-                .get_or_create(&labels)
-                .set(messages_sent as i64);
+        let bgp_neighbor_state = Family::<BGPNeighborStateLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_neighbor_state",
+            "State of BGP session",
+            bgp_neighbor_state.clone(),
+        );
 
-            bgp_in_q
-                .get_or_create(&labels)
-                .set(in_queue as i64);
+        let bgp_table_version = Family::<BGPRouterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_table_version",
+            "Version of BGP table",
+            bgp_table_version.clone(),
+        );
 
-            bgp_out_q
-                .get_or_create(&labels)
-                .set(out_queue as i64);
+        let bgp_as_path_entries = Family::<BGPRouterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_as_path_entries",
+            "Number of BGP AS-PATH entries",
+            bgp_as_path_entries.clone(),
+        );
+
+        let bgp_community_entries = Family::<BGPRouterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_community_entries",
+            "Number of BGP community entries",
+            bgp_community_entries.clone(),
+        );
+
+        let bgp_established_sessions = Family::<BGPRouterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_established_sessions",
+            "Number of established BGP sessions",
+            bgp_established_sessions.clone(),
+        );
+
+        let bgp_ebgp_maximum_paths = Family::<BGPRouterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_ebgp_maximum_paths",
+            "Configured maximum number of eBGP ECMP multipaths",
+            bgp_ebgp_maximum_paths.clone(),
+        );
+
+        let bgp_ibgp_maximum_paths = Family::<BGPRouterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_ibgp_maximum_paths",
+            "Configured maximum number of iBGP ECMP multipaths",
+            bgp_ibgp_maximum_paths.clone(),
+        );
+
+        let bgp_neighbor_info = Family::<BGPSessionInfoLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_neighbor_info",
+            "BGP neighbor info",
+            bgp_neighbor_info.clone(),
+        );
+
+        let bgp_connections_established_total = Family::<BGPSessionLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_connections_established_total",
+            "Total number of times BGP session was established",
+            bgp_connections_established_total.clone(),
+        );
+
+        let bgp_connections_dropped_total = Family::<BGPSessionLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_connections_dropped_total",
+            "Total number of times BGP session was dropped",
+            bgp_connections_dropped_total.clone(),
+        );
+
+        let bgp_hold_time_seconds = Family::<BGPSessionLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_hold_time_seconds",
+            "Hold time of BGP session",
+            bgp_hold_time_seconds.clone(),
+        );
+
+        let bgp_keepalive_interval_seconds = Family::<BGPSessionLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_keepalive_interval_seconds",
+            "Keepalive interval of BGP session",
+            bgp_keepalive_interval_seconds.clone(),
+        );
+
+        let bgp_message_type_received_total = Family::<BGPSessionMessageLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_message_type_received_total",
+            "Total number of BGP messages received by type",
+            bgp_message_type_received_total.clone(),
+        );
+
+        let bgp_message_type_sent_total = Family::<BGPSessionMessageLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_message_type_sent_total",
+            "Total number of BGP messages sent by type",
+            bgp_message_type_sent_total.clone(),
+        );
+
+        let bgp_prefix_accepted = Family::<BGPSessionAddressFamilyLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_prefix_accepted",
+            "Number of BGP prefixes accepted by address family",
+            bgp_prefix_accepted.clone(),
+        );
+
+        let bgp_prefix_advertised = Family::<BGPSessionAddressFamilyLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_bgp_prefix_advertised",
+            "Number of BGP prefixes advertised by address family",
+            bgp_prefix_advertised.clone(),
+        );
+
+        for table in self {
+            let labels = BGPRouterLabel::new(&table);
+            let status = &table.status;
 
-            bgp_session_seconds_total
+            bgp_table_version
                 .get_or_create(&labels)
-                .set(uptime as i64);
+                .set(status.table_version.into());
 
-            bgp_pfx_rcd
+            if let Some(as_paths) = status.as_paths {
+                bgp_as_path_entries
+                    .get_or_create(&labels)
+                    .set(as_paths as i64);
+            }
+
+            if let Some(communities) = status.communities {
+                bgp_community_entries
+                    .get_or_create(&labels)
+                    .set(communities as i64);
+            }
+
+            bgp_established_sessions
                 .get_or_create(&labels)
-                .set(prefixes_received as i64);
+                .set(status.sessions as i64);
+
+            if let Some(ebgp_maximum_paths) = status.ebgp_maximum_paths {
+                bgp_ebgp_maximum_paths
+                    .get_or_create(&labels)
+                    .set(ebgp_maximum_paths as i64);
+            }
+
+            if let Some(ibgp_maximum_paths) = status.ibgp_maximum_paths {
+                bgp_ibgp_maximum_paths
+                    .get_or_create(&labels)
+                    .set(ibgp_maximum_paths as i64);
+            }
+
+            for mut neighbor in table.status.neighbors {
+                // The summary shows the number of received prefixes in place of the state once established.
+                let state = neighbor.state.clone().unwrap_or_else(|| "Established".to_string());
+                let (
+                    messages_received,
+                    messages_sent,
+                    in_queue,
+                    out_queue,
+                    uptime,
+                    prefixes_received,
+                ) = (
+                    neighbor.messages_received,
+                    neighbor.messages_sent,
+                    neighbor.in_queue,
+                    neighbor.out_queue,
+                    neighbor.uptime.map(|d| d.as_secs()).unwrap_or_default(),
+                    neighbor.prefixes_received.unwrap_or_default(),
+                );
+                let detail = neighbor.detail.take();
+                let labels = labels.neighbor(&neighbor);
+
+                bgp_neighbor_up
+                    .get_or_create(&labels)
+                    .set((state == "Established").into());
+
+                for s in BGP_STATES {
+                    bgp_neighbor_state
+                        .get_or_create(&labels.clone().state(s))
+                        .set(state.starts_with(s).into());
+                }
+
+                bgp_msg_rcv
+                    .get_or_create(&labels)
+                    .set(messages_received as i64);
+
+                bgp_msg_sen
+                    .get_or_create(&labels)
+                    .set(messages_sent as i64);
+
+                bgp_in_q
+                    .get_or_create(&labels)
+                    .set(in_queue as i64);
+
+                bgp_out_q
+                    .get_or_create(&labels)
+                    .set(out_queue as i64);
+
+                bgp_session_seconds_total
+                    .get_or_create(&labels)
+                    .set(uptime as i64);
+
+                bgp_pfx_rcd
+                    .get_or_create(&labels)
+                    .set(prefixes_received as i64);
+
+                let Some(detail) = detail else {
+                    continue;
+                };
+                let labels = labels.session();
+
+                bgp_neighbor_info
+                    .get_or_create(&labels.clone().info(&detail))
+                    .set(1);
+
+                if let Some(connections_established) = detail.connections_established {
+                    bgp_connections_established_total
+                        .get_or_create(&labels)
+                        .set(connections_established as i64);
+                }
+
+                if let Some(connections_dropped) = detail.connections_dropped {
+                    bgp_connections_dropped_total
+                        .get_or_create(&labels)
+                        .set(connections_dropped as i64);
+                }
+
+                if let Some(hold_time) = detail.hold_time {
+                    bgp_hold_time_seconds
+                        .get_or_create(&labels)
+                        .set(hold_time.as_secs() as i64);
+                }
+
+                if let Some(keepalive_interval) = detail.keepalive_interval {
+                    bgp_keepalive_interval_seconds
+                        .get_or_create(&labels)
+                        .set(keepalive_interval.as_secs() as i64);
+                }
+
+                for (family, counters) in [(&bgp_message_type_received_total, &detail.messages_received), (&bgp_message_type_sent_total, &detail.messages_sent)] {
+                    let BGPMessageCounters { open, update, notification, keepalive, route_refresh } = counters;
+                    for (r#type, value) in [("open", open), ("update", update), ("notification", notification), ("keepalive", keepalive), ("route_refresh", route_refresh)] {
+                        if let Some(value) = value {
+                            family
+                                .get_or_create(&labels.clone().message(r#type))
+                                .set(*value as i64);
+                        }
+                    }
+                }
+
+                for address_family in detail.address_families {
+                    let labels = labels.clone().address_family(&address_family.address_family);
+
+                    if let Some(prefixes_accepted) = address_family.prefixes_accepted {
+                        bgp_prefix_accepted
+                            .get_or_create(&labels)
+                            .set(prefixes_accepted as i64);
+                    }
+
+                    if let Some(prefixes_advertised) = address_family.prefixes_advertised {
+                        bgp_prefix_advertised
+                            .get_or_create(&labels)
+                            .set(prefixes_advertised as i64);
+                    }
+                }
+            }
         }
     }
 }
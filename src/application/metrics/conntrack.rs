@@ -0,0 +1,121 @@
+use indexmap::IndexMap;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+
+use crate::{
+    application::metrics::{Collector, Gauge},
+    service::conntrack::ConntrackResult,
+};
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct ConntrackCpuLabel {
+    cpu: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct ConntrackEntryLabel {
+    protocol: String,
+    state: String,
+}
+
+impl Collector for ConntrackResult {
+    fn collect(self, registry: &mut Registry) {
+        let conntrack_entries = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_conntrack_entries",
+            "Number of entries in conntrack table",
+            conntrack_entries.clone(),
+        );
+
+        let conntrack_entries_limit = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_conntrack_entries_limit",
+            "Maximum size of conntrack table",
+            conntrack_entries_limit.clone(),
+        );
+
+        let conntrack_insert_failed_total = Family::<ConntrackCpuLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_conntrack_insert_failed_total",
+            "Total number of entries for which list insertion was attempted but failed",
+            conntrack_insert_failed_total.clone(),
+        );
+
+        let conntrack_drop_total = Family::<ConntrackCpuLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_conntrack_drop_total",
+            "Total number of packets dropped due to conntrack failure",
+            conntrack_drop_total.clone(),
+        );
+
+        let conntrack_early_drop_total = Family::<ConntrackCpuLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_conntrack_early_drop_total",
+            "Total number of dropped conntrack entries to make room for new ones when table is full",
+            conntrack_early_drop_total.clone(),
+        );
+
+        let conntrack_search_restart_total = Family::<ConntrackCpuLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_conntrack_search_restart_total",
+            "Total number of conntrack table lookups which had to be restarted due to hashtable resizes",
+            conntrack_search_restart_total.clone(),
+        );
+
+        conntrack_entries.set(self.count as i64);
+        conntrack_entries_limit.set(self.max as i64);
+
+        for statistics in self.statistics {
+            let labels = ConntrackCpuLabel {
+                cpu: statistics.cpu.to_string(),
+            };
+
+            conntrack_insert_failed_total
+                .get_or_create(&labels)
+                .set(statistics.insert_failed as i64);
+
+            conntrack_drop_total
+                .get_or_create(&labels)
+                .set(statistics.drop as i64);
+
+            conntrack_early_drop_total
+                .get_or_create(&labels)
+                .set(statistics.early_drop as i64);
+
+            if let Some(search_restart) = statistics.search_restart {
+                conntrack_search_restart_total
+                    .get_or_create(&labels)
+                    .set(search_restart as i64);
+            }
+        }
+
+        let Some(entries) = self.entries else {
+            return;
+        };
+
+        let conntrack_protocol_entries = Family::<ConntrackEntryLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_conntrack_protocol_entries",
+            "Number of entries in conntrack table by protocol and state",
+            conntrack_protocol_entries.clone(),
+        );
+
+        let mut counts = IndexMap::<_, i64>::new();
+        for entry in entries {
+            *counts.entry((entry.protocol, entry.state.unwrap_or_default())).or_default() += 1;
+        }
+
+        for ((protocol, state), count) in counts {
+            let labels = ConntrackEntryLabel {
+                protocol,
+                state,
+            };
+            conntrack_protocol_entries
+                .get_or_create(&labels)
+                .set(count);
+        }
+    }
+}
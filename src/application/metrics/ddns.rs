@@ -1,3 +1,6 @@
+use std::net::IpAddr;
+
+use chrono::Local;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::family::Family,
@@ -6,8 +9,8 @@ use prometheus_client::{
 
 use crate::{
     application::metrics::{Collector, Gauge},
-    domain::ddns::{DdnsStatus, DdnsUpdateStatus},
-    service::ddns::DdnsStatusResult,
+    domain::ddns::{DdnsRecord, DdnsStatus, DdnsUpdateStatus},
+    service::ddns::{DdnsRecordResult, DdnsStatusResult},
 };
 
 #[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
@@ -17,11 +20,66 @@ pub struct DdnsStatusLabel {
     hostname: String,
 }
 
-impl From<DdnsStatus> for DdnsStatusLabel {
-    fn from(s: DdnsStatus) -> Self {
-        let interface_name = s.interface;
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct DdnsUpdateStatusLabel {
+    interface_name: String,
+    ip_address: String,
+    hostname: String,
+    status: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct DdnsRecordLabel {
+    interface_name: String,
+    ip_address: String,
+    hostname: String,
+    resolved_address: String,
+}
+
+const DDNS_UPDATE_STATUSES: [DdnsUpdateStatus; 23] = [
+    DdnsUpdateStatus::Abuse,
+    DdnsUpdateStatus::BadAgent,
+    DdnsUpdateStatus::BadAuth,
+    DdnsUpdateStatus::BadSystemParameter,
+    DdnsUpdateStatus::Blocked,
+    DdnsUpdateStatus::DNSError,
+    DdnsUpdateStatus::Failed,
+    DdnsUpdateStatus::Good,
+    DdnsUpdateStatus::Illegal,
+    DdnsUpdateStatus::NoAccess,
+    DdnsUpdateStatus::NoChange,
+    DdnsUpdateStatus::NoConnect,
+    DdnsUpdateStatus::NoError,
+    DdnsUpdateStatus::NoFQDN,
+    DdnsUpdateStatus::NoHost,
+    DdnsUpdateStatus::NoService,
+    DdnsUpdateStatus::NotActive,
+    DdnsUpdateStatus::NotDonator,
+    DdnsUpdateStatus::NotDynamicHost,
+    DdnsUpdateStatus::NotYours,
+    DdnsUpdateStatus::NumHost,
+    DdnsUpdateStatus::TooSoon,
+    DdnsUpdateStatus::Unauthenticated,
+];
+
+impl From<&DdnsStatus> for DdnsStatusLabel {
+    fn from(s: &DdnsStatus) -> Self {
+        let interface_name = s.interface.clone();
         let ip_address = s.ip_address.map(|a| a.to_string()).unwrap_or_default();
-        let hostname = s.host_name.unwrap_or_default();
+        let hostname = s.host_name.clone().unwrap_or_default();
+        Self {
+            interface_name,
+            ip_address,
+            hostname,
+        }
+    }
+}
+
+impl From<&DdnsRecord> for DdnsStatusLabel {
+    fn from(r: &DdnsRecord) -> Self {
+        let interface_name = r.interface.clone();
+        let ip_address = r.ip_address.to_string();
+        let hostname = r.host_name.clone();
         Self {
             interface_name,
             ip_address,
@@ -30,7 +88,27 @@ impl From<DdnsStatus> for DdnsStatusLabel {
     }
 }
 
-impl Collector for DdnsStatusResult {
+impl DdnsStatusLabel {
+    pub fn status(self, status: &DdnsUpdateStatus) -> DdnsUpdateStatusLabel {
+        DdnsUpdateStatusLabel {
+            interface_name: self.interface_name,
+            ip_address: self.ip_address,
+            hostname: self.hostname,
+            status: status.to_string(),
+        }
+    }
+
+    pub fn resolved(self, address: &IpAddr) -> DdnsRecordLabel {
+        DdnsRecordLabel {
+            interface_name: self.interface_name,
+            ip_address: self.ip_address,
+            hostname: self.hostname,
+            resolved_address: address.to_string(),
+        }
+    }
+}
+
+impl Collector for (DdnsStatusResult, Option<DdnsRecordResult>) {
     fn collect(self, registry: &mut Registry) {
         let ddns_status = Family::<DdnsStatusLabel, Gauge>::default();
         registry.register(
@@ -39,12 +117,84 @@ impl Collector for DdnsStatusResult {
             ddns_status.clone(),
         );
 
-        for status in self {
+        let ddns_update_status = Family::<DdnsUpdateStatusLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dynamic_dns_update_status",
+            "Status code returned by DDNS provider on last update",
+            ddns_update_status.clone(),
+        );
+
+        let ddns_last_update_timestamp_seconds = Family::<DdnsStatusLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dynamic_dns_last_update_timestamp_seconds",
+            "Unix timestamp of last DDNS update",
+            ddns_last_update_timestamp_seconds.clone(),
+        );
+
+        let (statuses, records) = self;
+        for status in statuses {
+            let labels = DdnsStatusLabel::from(&status);
             let value = match status.update_status {
                 Some(DdnsUpdateStatus::Good | DdnsUpdateStatus::NoChange | DdnsUpdateStatus::NoError) => 1,
                 _ => 0,
             };
-            ddns_status.get_or_create(&status.into()).set(value);
+
+            ddns_status
+                .get_or_create(&labels)
+                .set(value);
+
+            if let Some(update_status) = &status.update_status {
+                for s in &DDNS_UPDATE_STATUSES {
+                    ddns_update_status
+                        .get_or_create(&labels.clone().status(s))
+                        .set((update_status == s).into());
+                }
+
+                // Statuses unknown to the exporter are exported as is in addition to the known ones.
+                if let DdnsUpdateStatus::Unknown(_) = update_status {
+                    ddns_update_status
+                        .get_or_create(&labels.clone().status(update_status))
+                        .set(1);
+                }
+            }
+
+            if let Some(last_update) = status.last_update.and_then(|u| u.and_local_timezone(Local).earliest()) {
+                ddns_last_update_timestamp_seconds
+                    .get_or_create(&labels)
+                    .set(last_update.timestamp());
+            }
+        }
+
+        let Some(records) = records else {
+            return;
+        };
+
+        let ddns_record_matches = Family::<DdnsStatusLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dynamic_dns_record_matches",
+            "Whether DDNS host name resolves to IP address of interface",
+            ddns_record_matches.clone(),
+        );
+
+        let ddns_record_info = Family::<DdnsRecordLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dynamic_dns_record_info",
+            "Address that DDNS host name resolves to",
+            ddns_record_info.clone(),
+        );
+
+        for record in records {
+            let labels = DdnsStatusLabel::from(&record);
+
+            ddns_record_matches
+                .get_or_create(&labels)
+                .set(record.addresses.contains(&record.ip_address).into());
+
+            for address in &record.addresses {
+                ddns_record_info
+                    .get_or_create(&labels.clone().resolved(address))
+                    .set(1);
+            }
         }
     }
 }
@@ -0,0 +1,113 @@
+use chrono::Local;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+
+use crate::{
+    application::metrics::{Collector, Gauge},
+    domain::dhcp::DhcpLease,
+    service::dhcp::{DhcpLeaseResult, DhcpStatisticsResult},
+};
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct DhcpPoolLabel {
+    pool: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct DhcpLeaseLabel {
+    pool: String,
+    ip_address: String,
+    mac_address: String,
+    hostname: String,
+}
+
+impl From<&DhcpLease> for DhcpLeaseLabel {
+    fn from(l: &DhcpLease) -> Self {
+        Self {
+            pool: l.pool.clone(),
+            ip_address: l.ip_address.to_string(),
+            mac_address: l.mac_address.clone(),
+            hostname: l.client_name.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl Collector for (DhcpStatisticsResult, Option<DhcpLeaseResult>) {
+    fn collect(self, registry: &mut Registry) {
+        let dhcp_pool_size = Family::<DhcpPoolLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dhcp_pool_size",
+            "Number of addresses in DHCP pool",
+            dhcp_pool_size.clone(),
+        );
+
+        let dhcp_pool_leased = Family::<DhcpPoolLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dhcp_pool_leased",
+            "Number of leased addresses in DHCP pool",
+            dhcp_pool_leased.clone(),
+        );
+
+        let dhcp_pool_available = Family::<DhcpPoolLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dhcp_pool_available",
+            "Number of available addresses in DHCP pool",
+            dhcp_pool_available.clone(),
+        );
+
+        let (pools, leases) = self;
+        for pool in pools {
+            let labels = DhcpPoolLabel {
+                pool: pool.pool,
+            };
+
+            dhcp_pool_size
+                .get_or_create(&labels)
+                .set(pool.pool_size as i64);
+
+            dhcp_pool_leased
+                .get_or_create(&labels)
+                .set(pool.leased as i64);
+
+            dhcp_pool_available
+                .get_or_create(&labels)
+                .set(pool.available as i64);
+        }
+
+        let Some(leases) = leases else {
+            return;
+        };
+
+        let dhcp_lease_info = Family::<DhcpLeaseLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dhcp_lease_info",
+            "DHCP lease info",
+            dhcp_lease_info.clone(),
+        );
+
+        let dhcp_lease_expiry_timestamp_seconds = Family::<DhcpLeaseLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_dhcp_lease_expiry_timestamp_seconds",
+            "Unix timestamp when DHCP lease expires",
+            dhcp_lease_expiry_timestamp_seconds.clone(),
+        );
+
+        for lease in leases {
+            let labels = (&lease).into();
+
+            dhcp_lease_info
+                .get_or_create(&labels)
+                .set(1);
+
+            // Lease expiration is shown in the local time of the router.
+            if let Some(expiration) = lease.expiration.and_then(|e| e.and_local_timezone(Local).earliest()) {
+                dhcp_lease_expiry_timestamp_seconds
+                    .get_or_create(&labels)
+                    .set(expiration.timestamp());
+            }
+        }
+    }
+}
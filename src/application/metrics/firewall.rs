@@ -0,0 +1,108 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+
+use crate::{
+    application::metrics::{Collector, Gauge},
+    domain::firewall::NatRule,
+    service::firewall::{FirewallResult, NatResult},
+};
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct FirewallRuleLabel {
+    ip_version: String,
+    ruleset: String,
+    rule: String,
+    action: String,
+    description: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct NatRuleLabel {
+    rule: String,
+    r#type: String,
+    inbound_interface: String,
+    outbound_interface: String,
+    description: String,
+}
+
+impl From<NatRule> for NatRuleLabel {
+    fn from(r: NatRule) -> Self {
+        Self {
+            rule: r.rule.to_string(),
+            r#type: r.nat_type,
+            inbound_interface: r.inbound_interface.unwrap_or_default(),
+            outbound_interface: r.outbound_interface.unwrap_or_default(),
+            description: r.description.unwrap_or_default(),
+        }
+    }
+}
+
+impl Collector for (FirewallResult, NatResult) {
+    fn collect(self, registry: &mut Registry) {
+        let firewall_rule_packets_total = Family::<FirewallRuleLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_firewall_rule_packets_total",
+            "Total packets matched by firewall rule",
+            firewall_rule_packets_total.clone(),
+        );
+
+        let firewall_rule_bytes_total = Family::<FirewallRuleLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_firewall_rule_bytes_total",
+            "Total bytes matched by firewall rule",
+            firewall_rule_bytes_total.clone(),
+        );
+
+        let nat_rule_packets_total = Family::<NatRuleLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_nat_rule_packets_total",
+            "Total packets matched by NAT rule",
+            nat_rule_packets_total.clone(),
+        );
+
+        let nat_rule_bytes_total = Family::<NatRuleLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_nat_rule_bytes_total",
+            "Total bytes matched by NAT rule",
+            nat_rule_bytes_total.clone(),
+        );
+
+        let (rulesets, nat_rules) = self;
+        for ruleset in rulesets {
+            for rule in ruleset.rules {
+                let (packets, bytes): (u64, u64) = (rule.packets.into(), rule.bytes.into());
+                let labels = FirewallRuleLabel {
+                    ip_version: ruleset.ip_version.clone(),
+                    ruleset: ruleset.name.clone(),
+                    rule: rule.rule.to_string(),
+                    action: rule.action,
+                    description: rule.description.unwrap_or_default(),
+                };
+
+                firewall_rule_packets_total
+                    .get_or_create(&labels)
+                    .set(packets as i64);
+
+                firewall_rule_bytes_total
+                    .get_or_create(&labels)
+                    .set(bytes as i64);
+            }
+        }
+
+        for rule in nat_rules {
+            let (packets, bytes): (u64, u64) = (rule.packets.clone().into(), rule.bytes.clone().into());
+            let labels = rule.into();
+
+            nat_rule_packets_total
+                .get_or_create(&labels)
+                .set(packets as i64);
+
+            nat_rule_bytes_total
+                .get_or_create(&labels)
+                .set(bytes as i64);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+
+use crate::{
+    application::metrics::{Collector, Gauge},
+    domain::interface::AddrInfo,
+    service::interface::InterfaceStatisticsResult,
+};
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct InterfaceLabel {
+    interface_name: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct InterfaceInfoLabel {
+    interface_name: String,
+    operstate: String,
+    ip_address: String,
+    peer_ip_address: String,
+}
+
+impl InterfaceLabel {
+    pub fn info(self, operstate: String, addr_info: Option<&AddrInfo>) -> InterfaceInfoLabel {
+        let interface_name = self.interface_name;
+        let ip_address = addr_info.map(|a| format!("{}/{}", a.local, a.prefixlen)).unwrap_or_default();
+        let peer_ip_address = addr_info.and_then(|a| a.address).map(|a| a.to_string()).unwrap_or_default();
+        InterfaceInfoLabel {
+            interface_name,
+            operstate,
+            ip_address,
+            peer_ip_address,
+        }
+    }
+}
+
+impl Collector for InterfaceStatisticsResult {
+    fn collect(self, registry: &mut Registry) {
+        let interface_info = Family::<InterfaceInfoLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_info",
+            "Interface info",
+            interface_info.clone(),
+        );
+
+        let interface_up = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_up",
+            "Whether the operational state of interface is up",
+            interface_up.clone(),
+        );
+
+        let interface_mtu_bytes = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_mtu_bytes",
+            "MTU of interface",
+            interface_mtu_bytes.clone(),
+        );
+
+        let interface_speed_bytes = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_speed_bytes",
+            "Speed of interface in bytes per second",
+            interface_speed_bytes.clone(),
+        );
+
+        let interface_carrier_changes_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_carrier_changes_total",
+            "Total number of carrier changes for interface",
+            interface_carrier_changes_total.clone(),
+        );
+
+        let interface_receive_bytes_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_receive_bytes_total",
+            "Total receive bytes for interface",
+            interface_receive_bytes_total.clone(),
+        );
+
+        let interface_receive_packets_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_receive_packets_total",
+            "Total receive packets for interface",
+            interface_receive_packets_total.clone(),
+        );
+
+        let interface_receive_errors_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_receive_errors_total",
+            "Total receive errors for interface",
+            interface_receive_errors_total.clone(),
+        );
+
+        let interface_receive_dropped_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_receive_dropped_total",
+            "Total receive drops for interface",
+            interface_receive_dropped_total.clone(),
+        );
+
+        let interface_receive_multicast_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_receive_multicast_total",
+            "Total receive multicast packets for interface",
+            interface_receive_multicast_total.clone(),
+        );
+
+        let interface_transmit_bytes_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_transmit_bytes_total",
+            "Total transmit bytes for interface",
+            interface_transmit_bytes_total.clone(),
+        );
+
+        let interface_transmit_packets_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_transmit_packets_total",
+            "Total transmit packets for interface",
+            interface_transmit_packets_total.clone(),
+        );
+
+        let interface_transmit_errors_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_transmit_errors_total",
+            "Total transmit errors for interface",
+            interface_transmit_errors_total.clone(),
+        );
+
+        let interface_transmit_dropped_total = Family::<InterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_interface_transmit_dropped_total",
+            "Total transmit drops for interface",
+            interface_transmit_dropped_total.clone(),
+        );
+
+        for statistics in self {
+            let interface = statistics.interface;
+            let counters = statistics.counters;
+            let labels = InterfaceLabel {
+                interface_name: counters.ifname,
+            };
+
+            if interface.addr_info.is_empty() {
+                interface_info
+                    .get_or_create(&labels.clone().info(interface.operstate.clone(), None))
+                    .set(1);
+            }
+            for addr_info in &interface.addr_info {
+                interface_info
+                    .get_or_create(&labels.clone().info(interface.operstate.clone(), Some(addr_info)))
+                    .set(1);
+            }
+
+            interface_up
+                .get_or_create(&labels)
+                .set((interface.operstate == "UP").into());
+
+            if let Some(mtu) = statistics.mtu {
+                interface_mtu_bytes
+                    .get_or_create(&labels)
+                    .set(mtu.into());
+            }
+
+            if let Some(speed) = statistics.speed {
+                interface_speed_bytes
+                    .get_or_create(&labels)
+                    .set((speed * 1000 * 1000 / 8) as i64);
+            }
+
+            if let Some(carrier_changes) = statistics.carrier_changes {
+                interface_carrier_changes_total
+                    .get_or_create(&labels)
+                    .set(carrier_changes as i64);
+            }
+
+            interface_receive_bytes_total
+                .get_or_create(&labels)
+                .set(counters.receive_bytes as i64);
+
+            interface_receive_packets_total
+                .get_or_create(&labels)
+                .set(counters.receive_packets as i64);
+
+            interface_receive_errors_total
+                .get_or_create(&labels)
+                .set(counters.receive_errors as i64);
+
+            interface_receive_dropped_total
+                .get_or_create(&labels)
+                .set(counters.receive_dropped as i64);
+
+            interface_receive_multicast_total
+                .get_or_create(&labels)
+                .set(counters.receive_multicast as i64);
+
+            interface_transmit_bytes_total
+                .get_or_create(&labels)
+                .set(counters.transmit_bytes as i64);
+
+            interface_transmit_packets_total
+                .get_or_create(&labels)
+                .set(counters.transmit_packets as i64);
+
+            interface_transmit_errors_total
+                .get_or_create(&labels)
+                .set(counters.transmit_errors as i64);
+
+            interface_transmit_dropped_total
+                .get_or_create(&labels)
+                .set(counters.transmit_dropped as i64);
+        }
+    }
+}
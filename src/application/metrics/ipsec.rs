@@ -1,3 +1,5 @@
+use std::time::UNIX_EPOCH;
+
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::family::Family,
@@ -5,31 +7,184 @@ use prometheus_client::{
 };
 
 use crate::{
-    application::metrics::{Collector, Gauge},
-    domain::ipsec::{ChildSAState, SA, SAState},
-    service::ipsec::IPsecResult,
+    application::metrics::{atomic, Collector, Gauge},
+    domain::ipsec::{ChildConn, ChildSA, ChildSAState, SA, SAState},
+    service::ipsec::{IPsecBackendResult, IPsecConnResult, IPsecCounterResult, IPsecEventResult, IPsecResult, IPsecStatsResult},
 };
 
 #[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
 pub struct IPsecTunnelLabel {
     tunnel: String,
+    ike_sa: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecChildSALabel {
+    tunnel: String,
+    ike_sa: String,
+    reqid: String,
+    local_ts: String,
+    remote_ts: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecTunnelInfoLabel {
+    tunnel: String,
+    ike_sa: String,
+    reqid: String,
+    local_ts: String,
+    remote_ts: String,
+    mode: String,
+    protocol: String,
+    encr_alg: String,
+    encr_keysize: String,
+    integ_alg: String,
+    integ_keysize: String,
+    prf_alg: String,
+    dh_group: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecIKESALabel {
+    ike_sa: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecIKESAInfoLabel {
+    ike_sa: String,
+    version: String,
+    local_host: String,
+    remote_host: String,
+    encr_alg: String,
+    encr_keysize: String,
+    integ_alg: String,
+    integ_keysize: String,
+    prf_alg: String,
+    dh_group: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecBackendLabel {
+    backend: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecEventLabel {
+    ike_sa: String,
+    tunnel: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecEventTypeLabel {
+    ike_sa: String,
+    tunnel: String,
+    event: String,
 }
 
-impl From<SA> for IPsecTunnelLabel {
-    fn from(sa: SA) -> Self {
-        let tunnel = sa.child_sas.into_values()
-            .map(|c| c.name)
-            .next()
-            .unwrap_or_default();
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecPriorityLabel {
+    priority: String,
+}
 
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct IPsecCounterLabel {
+    counter: String,
+}
+
+impl IPsecTunnelLabel {
+    pub fn new(ike_sa: &str, tunnel: Option<&str>) -> Self {
+        let tunnel = tunnel.unwrap_or_default().to_string();
+        let ike_sa = ike_sa.to_string();
         Self {
             tunnel,
+            ike_sa,
+        }
+    }
+
+    pub fn child_sa(self, child_sa: Option<&ChildSA>) -> IPsecChildSALabel {
+        IPsecChildSALabel {
+            tunnel: self.tunnel,
+            ike_sa: self.ike_sa,
+            reqid: child_sa.map(|c| c.reqid.to_string()).unwrap_or_default(),
+            local_ts: child_sa.map(|c| c.local_ts.join(",")).unwrap_or_default(),
+            remote_ts: child_sa.map(|c| c.remote_ts.join(",")).unwrap_or_default(),
+        }
+    }
+
+    pub fn configured(self, c: &ChildConn) -> IPsecChildSALabel {
+        IPsecChildSALabel {
+            tunnel: self.tunnel,
+            ike_sa: self.ike_sa,
+            reqid: String::new(),
+            local_ts: c.local_ts.join(","),
+            remote_ts: c.remote_ts.join(","),
+        }
+    }
+}
+
+impl IPsecChildSALabel {
+    pub fn info(self, c: &ChildSA) -> IPsecTunnelInfoLabel {
+        IPsecTunnelInfoLabel {
+            tunnel: self.tunnel,
+            ike_sa: self.ike_sa,
+            reqid: self.reqid,
+            local_ts: self.local_ts,
+            remote_ts: self.remote_ts,
+            mode: c.mode.clone(),
+            protocol: c.protocol.clone().unwrap_or_default(),
+            encr_alg: c.encr_alg.clone().unwrap_or_default(),
+            encr_keysize: c.encr_keysize.map(|k| k.to_string()).unwrap_or_default(),
+            integ_alg: c.integ_alg.clone().unwrap_or_default(),
+            integ_keysize: c.integ_keysize.map(|k| k.to_string()).unwrap_or_default(),
+            prf_alg: c.prf_alg.clone().unwrap_or_default(),
+            dh_group: c.dh_group.clone().unwrap_or_default(),
         }
     }
 }
 
-impl Collector for IPsecResult {
+impl IPsecIKESALabel {
+    pub fn new(ike_sa: &str) -> Self {
+        let ike_sa = ike_sa.to_string();
+        Self {
+            ike_sa,
+        }
+    }
+
+    pub fn info(self, sa: &SA) -> IPsecIKESAInfoLabel {
+        IPsecIKESAInfoLabel {
+            ike_sa: self.ike_sa,
+            version: sa.version.clone(),
+            local_host: sa.local_host.clone(),
+            remote_host: sa.remote_host.clone(),
+            encr_alg: sa.encr_alg.clone().unwrap_or_default(),
+            encr_keysize: sa.encr_keysize.map(|k| k.to_string()).unwrap_or_default(),
+            integ_alg: sa.integ_alg.clone().unwrap_or_default(),
+            integ_keysize: sa.integ_keysize.map(|k| k.to_string()).unwrap_or_default(),
+            prf_alg: sa.prf_alg.clone().unwrap_or_default(),
+            dh_group: sa.dh_group.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl IPsecEventLabel {
+    pub fn event(self, event: &str) -> IPsecEventTypeLabel {
+        IPsecEventTypeLabel {
+            ike_sa: self.ike_sa,
+            tunnel: self.tunnel,
+            event: event.to_string(),
+        }
+    }
+}
+
+impl Collector for (IPsecResult, IPsecConnResult, IPsecStatsResult, IPsecCounterResult, IPsecEventResult, IPsecBackendResult) {
     fn collect(self, registry: &mut Registry) {
+        let ipsec_info = Family::<IPsecBackendLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_info",
+            "Backend used to retrieve IPsec SAs",
+            ipsec_info.clone(),
+        );
+
         let ipsec_up = Family::<IPsecTunnelLabel, Gauge>::default();
         registry.register(
             "ipsec_up",
@@ -44,78 +199,383 @@ impl Collector for IPsecResult {
             ipsec_status.clone(),
         );
 
-        let ipsec_in_bytes = Family::<IPsecTunnelLabel, Gauge>::default();
+        let ipsec_in_bytes = Family::<IPsecChildSALabel, Gauge>::default();
         registry.register(
             "ipsec_in_bytes",
             "Total receive bytes for IPsec tunnel",
             ipsec_in_bytes.clone(),
         );
 
-        let ipsec_out_bytes = Family::<IPsecTunnelLabel, Gauge>::default();
+        let ipsec_out_bytes = Family::<IPsecChildSALabel, Gauge>::default();
         registry.register(
             "ipsec_out_bytes",
             "Total transmit bytes for IPsec tunnel",
             ipsec_out_bytes.clone(),
         );
 
-        let ipsec_in_packets = Family::<IPsecTunnelLabel, Gauge>::default();
+        let ipsec_in_packets = Family::<IPsecChildSALabel, Gauge>::default();
         registry.register(
             "ipsec_in_packets",
             "Total receive packets for IPsec tunnel",
             ipsec_in_packets.clone(),
         );
 
-        let ipsec_out_packets = Family::<IPsecTunnelLabel, Gauge>::default();
+        let ipsec_out_packets = Family::<IPsecChildSALabel, Gauge>::default();
         registry.register(
             "ipsec_out_packets",
             "Total transmit packets for IPsec tunnel",
             ipsec_out_packets.clone(),
         );
 
-        for sa in self.into_values() {
-            let child_sa = sa.child_sas.values().next();
-            let (
-                in_bytes,
-                out_bytes,
-                in_packets,
-                out_packets,
-            ) = (
-                child_sa.and_then(|c| c.bytes_in).unwrap_or_default(),
-                child_sa.and_then(|c| c.bytes_out).unwrap_or_default(),
-                child_sa.and_then(|c| c.packets_in).unwrap_or_default(),
-                child_sa.and_then(|c| c.packets_out).unwrap_or_default(),
+        let ipsec_worker_threads_active = Family::<IPsecPriorityLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_worker_threads_active",
+            "Number of worker threads of strongSwan processing jobs by priority",
+            ipsec_worker_threads_active.clone(),
+        );
+
+        let ipsec_job_queue = Family::<IPsecPriorityLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_job_queue",
+            "Number of queued jobs of strongSwan by priority",
+            ipsec_job_queue.clone(),
+        );
+
+        let ipsec_counters_total = Family::<IPsecCounterLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_counters_total",
+            "Total number of IKE events counted by strongSwan",
+            ipsec_counters_total.clone(),
+        );
+
+        let ipsec_ike_sa_info = Family::<IPsecIKESAInfoLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_ike_sa_info",
+            "Negotiated algorithms of IKE SA",
+            ipsec_ike_sa_info.clone(),
+        );
+
+        let ipsec_ike_sa_established_seconds = Family::<IPsecIKESALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_ike_sa_established_seconds",
+            "Seconds since IKE SA was established",
+            ipsec_ike_sa_established_seconds.clone(),
+        );
+
+        let ipsec_ike_sa_rekey_seconds = Family::<IPsecIKESALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_ike_sa_rekey_seconds",
+            "Seconds until IKE SA is rekeyed",
+            ipsec_ike_sa_rekey_seconds.clone(),
+        );
+
+        let ipsec_ike_sa_reauth_seconds = Family::<IPsecIKESALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_ike_sa_reauth_seconds",
+            "Seconds until IKE SA is reauthenticated",
+            ipsec_ike_sa_reauth_seconds.clone(),
+        );
+
+        let ipsec_tunnel_info = Family::<IPsecChildSALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_tunnel_info",
+            "Request ID and traffic selectors of IPsec tunnel",
+            ipsec_tunnel_info.clone(),
+        );
+
+        let ipsec_child_sa_info = Family::<IPsecTunnelInfoLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_child_sa_info",
+            "Negotiated algorithms of child SA",
+            ipsec_child_sa_info.clone(),
+        );
+
+        let ipsec_child_sa_installed_seconds = Family::<IPsecChildSALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_child_sa_installed_seconds",
+            "Seconds since child SA was installed",
+            ipsec_child_sa_installed_seconds.clone(),
+        );
+
+        let ipsec_child_sa_rekey_seconds = Family::<IPsecChildSALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_child_sa_rekey_seconds",
+            "Seconds until child SA is rekeyed",
+            ipsec_child_sa_rekey_seconds.clone(),
+        );
+
+        let ipsec_child_sa_lifetime_seconds = Family::<IPsecChildSALabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_child_sa_lifetime_seconds",
+            "Seconds until child SA expires",
+            ipsec_child_sa_lifetime_seconds.clone(),
+        );
+
+        let ipsec_events_total = Family::<IPsecEventTypeLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ipsec_events_total",
+            "Total number of IPsec tunnel events observed since the exporter started",
+            ipsec_events_total.clone(),
+        );
+
+        let ipsec_last_change_timestamp_seconds = Family::<IPsecEventLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_ipsec_last_change_timestamp_seconds",
+            "Unix timestamp of the last IPsec tunnel event",
+            ipsec_last_change_timestamp_seconds.clone(),
+        );
+
+        let (sas, conns, stats, counters, events, backend) = self;
+        let labels = IPsecBackendLabel {
+            backend: backend.to_string(),
+        };
+
+        ipsec_info
+            .get_or_create(&labels)
+            .set(1);
+
+        for event in events {
+            let labels = IPsecEventLabel {
+                ike_sa: event.ike_sa,
+                tunnel: event.tunnel,
+            };
+
+            ipsec_events_total
+                .get_or_create(&labels.clone().event("up"))
+                .set(event.up as i64);
+
+            ipsec_events_total
+                .get_or_create(&labels.clone().event("down"))
+                .set(event.down as i64);
+
+            ipsec_events_total
+                .get_or_create(&labels.clone().event("rekey"))
+                .set(event.rekey as i64);
+
+            if let Some(last_change) = event.last_change.and_then(|t| t.duration_since(UNIX_EPOCH).ok()) {
+                ipsec_last_change_timestamp_seconds
+                    .get_or_create(&labels)
+                    .set(last_change.as_secs_f64());
+            }
+        }
+
+        if let Some(stats) = stats {
+            let ipsec_worker_threads = Gauge::<i64>::default();
+            registry.register(
+                "edgerouter_ipsec_worker_threads",
+                "Number of worker threads of strongSwan",
+                ipsec_worker_threads.clone(),
             );
-            let status = match (&sa.state, child_sa.map(|c| &c.state)) {
-                (SAState::Unknown, _) | (_, Some(ChildSAState::Unknown) | None) => 3,
-                (SAState::Established, Some(ChildSAState::Installed | ChildSAState::Rekeying | ChildSAState::Rekeyed)) => 0,
-                (SAState::Established, Some(_)) => 1,
-                _ => 2,
+
+            let ipsec_worker_threads_idle = Gauge::<i64>::default();
+            registry.register(
+                "edgerouter_ipsec_worker_threads_idle",
+                "Number of idle worker threads of strongSwan",
+                ipsec_worker_threads_idle.clone(),
+            );
+
+            let ipsec_scheduled_jobs = Gauge::<i64>::default();
+            registry.register(
+                "edgerouter_ipsec_scheduled_jobs",
+                "Number of scheduled jobs of strongSwan",
+                ipsec_scheduled_jobs.clone(),
+            );
+
+            let ipsec_ike_sas = Gauge::<i64>::default();
+            registry.register(
+                "edgerouter_ipsec_ike_sas",
+                "Number of IKE SAs",
+                ipsec_ike_sas.clone(),
+            );
+
+            let ipsec_ike_sas_half_open = Gauge::<i64>::default();
+            registry.register(
+                "edgerouter_ipsec_ike_sas_half_open",
+                "Number of half-open IKE SAs",
+                ipsec_ike_sas_half_open.clone(),
+            );
+
+            ipsec_worker_threads.set(stats.workers.total as i64);
+            ipsec_worker_threads_idle.set(stats.workers.idle as i64);
+            ipsec_scheduled_jobs.set(stats.scheduled as i64);
+            ipsec_ike_sas.set(stats.ikesas.total as i64);
+            ipsec_ike_sas_half_open.set(stats.ikesas.half_open as i64);
+
+            for (priority, active) in stats.workers.active.iter() {
+                let labels = IPsecPriorityLabel {
+                    priority: priority.to_string(),
+                };
+
+                ipsec_worker_threads_active
+                    .get_or_create(&labels)
+                    .set(active as i64);
+            }
+
+            for (priority, queued) in stats.queues.iter() {
+                let labels = IPsecPriorityLabel {
+                    priority: priority.to_string(),
+                };
+
+                ipsec_job_queue
+                    .get_or_create(&labels)
+                    .set(queued as i64);
+            }
+        }
+
+        for (counter, value) in counters {
+            let labels = IPsecCounterLabel {
+                counter,
             };
-            let labels = sa.into();
 
-            ipsec_up
+            ipsec_counters_total
                 .get_or_create(&labels)
+                .set(value as i64);
+        }
+
+        for (name, sa) in &sas {
+            let labels = IPsecIKESALabel::new(name);
+
+            ipsec_ike_sa_info
+                .get_or_create(&labels.clone().info(sa))
                 .set(1);
 
-            ipsec_status
-                .get_or_create(&labels)
-                .set(status);
+            if let Some(established) = sa.established {
+                ipsec_ike_sa_established_seconds
+                    .get_or_create(&labels)
+                    .set(established as i64);
+            }
 
-            ipsec_in_bytes
-                .get_or_create(&labels)
-                .set(in_bytes as i64);
+            if let Some(rekey_time) = sa.rekey_time {
+                ipsec_ike_sa_rekey_seconds
+                    .get_or_create(&labels)
+                    .set(rekey_time as i64);
+            }
 
-            ipsec_out_bytes
-                .get_or_create(&labels)
-                .set(out_bytes as i64);
+            if let Some(reauth_time) = sa.reauth_time {
+                ipsec_ike_sa_reauth_seconds
+                    .get_or_create(&labels)
+                    .set(reauth_time as i64);
+            }
 
-            ipsec_in_packets
-                .get_or_create(&labels)
-                .set(in_packets as i64);
+            // IKE SAs without any child SAs are still exported to tell their status.
+            let child_sas: Vec<_> = if sa.child_sas.is_empty() {
+                vec![None]
+            } else {
+                sa.child_sas.values().map(Some).collect()
+            };
 
-            ipsec_out_packets
-                .get_or_create(&labels)
-                .set(out_packets as i64);
+            for child_sa in child_sas {
+                let (
+                    in_bytes,
+                    out_bytes,
+                    in_packets,
+                    out_packets,
+                ) = (
+                    child_sa.and_then(|c| c.bytes_in).unwrap_or_default(),
+                    child_sa.and_then(|c| c.bytes_out).unwrap_or_default(),
+                    child_sa.and_then(|c| c.packets_in).unwrap_or_default(),
+                    child_sa.and_then(|c| c.packets_out).unwrap_or_default(),
+                );
+                let status = match (&sa.state, child_sa.map(|c| &c.state)) {
+                    (SAState::Unknown, _) | (_, Some(ChildSAState::Unknown) | None) => 3,
+                    (SAState::Established, Some(ChildSAState::Installed | ChildSAState::Rekeying | ChildSAState::Rekeyed)) => 0,
+                    (SAState::Established, Some(_)) => 1,
+                    _ => 2,
+                };
+                let labels = IPsecTunnelLabel::new(name, child_sa.map(|c| c.name.as_str()));
+
+                ipsec_up
+                    .get_or_create(&labels)
+                    .set(1);
+
+                ipsec_status
+                    .get_or_create(&labels)
+                    .set(status);
+
+                let labels = labels.child_sa(child_sa);
+
+                ipsec_in_bytes
+                    .get_or_create(&labels)
+                    .set(in_bytes as i64);
+
+                ipsec_out_bytes
+                    .get_or_create(&labels)
+                    .set(out_bytes as i64);
+
+                ipsec_in_packets
+                    .get_or_create(&labels)
+                    .set(in_packets as i64);
+
+                ipsec_out_packets
+                    .get_or_create(&labels)
+                    .set(out_packets as i64);
+
+                let Some(child_sa) = child_sa else {
+                    continue;
+                };
+
+                ipsec_tunnel_info
+                    .get_or_create(&labels)
+                    .set(1);
+
+                ipsec_child_sa_info
+                    .get_or_create(&labels.clone().info(child_sa))
+                    .set(1);
+
+                if let Some(install_time) = child_sa.install_time {
+                    ipsec_child_sa_installed_seconds
+                        .get_or_create(&labels)
+                        .set(install_time as i64);
+                }
+
+                if let Some(rekey_time) = child_sa.rekey_time {
+                    ipsec_child_sa_rekey_seconds
+                        .get_or_create(&labels)
+                        .set(rekey_time as i64);
+                }
+
+                if let Some(life_time) = child_sa.life_time {
+                    ipsec_child_sa_lifetime_seconds
+                        .get_or_create(&labels)
+                        .set(life_time as i64);
+                }
+            }
+        }
+
+        // Configured connections without corresponding SAs are exported as down.
+        for (name, conn) in &conns {
+            let sa = sas.get(name);
+            let children: Vec<_> = if conn.children.is_empty() {
+                vec![None]
+            } else {
+                conn.children.iter().map(Some).collect()
+            };
+
+            for child in children {
+                let active = match (sa, child) {
+                    (None, _) => false,
+                    (Some(_), None) => true,
+                    (Some(sa), Some((child_name, _))) => sa.child_sas.values().any(|c| &c.name == child_name),
+                };
+                if active {
+                    continue;
+                }
+                let labels = IPsecTunnelLabel::new(name, child.map(|(child_name, _)| child_name.as_str()));
+
+                ipsec_up
+                    .get_or_create(&labels)
+                    .set(0);
+
+                ipsec_status
+                    .get_or_create(&labels)
+                    .set(2);
+
+                if let Some((_, child)) = child {
+                    ipsec_tunnel_info
+                        .get_or_create(&labels.configured(child))
+                        .set(1);
+                }
+            }
         }
     }
 }
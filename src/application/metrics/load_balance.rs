@@ -1,3 +1,4 @@
+use chrono::Local;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::family::Family,
@@ -18,6 +19,11 @@ pub struct LoadBalanceHealthLabelBuilder {
     interface_name: String,
 }
 
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct LoadBalanceGroupLabel {
+    group_name: String,
+}
+
 #[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
 pub struct LoadBalanceHealthLabel {
     group_name: String,
@@ -113,6 +119,48 @@ impl Collector for LoadBalanceStatusResult {
             load_balancer_run_fail_total.clone(),
         );
 
+        let load_balancer_run_fail_limit = Family::<LoadBalanceHealthLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_run_fail_limit",
+            "Number of run failures to drop route",
+            load_balancer_run_fail_limit.clone(),
+        );
+
+        let load_balancer_recovery_successes = Family::<LoadBalanceHealthLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_recovery_successes",
+            "Number of successful runs while waiting on recovery",
+            load_balancer_recovery_successes.clone(),
+        );
+
+        let load_balancer_recovery_successes_required = Family::<LoadBalanceHealthLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_recovery_successes_required",
+            "Number of successful runs to recover route",
+            load_balancer_recovery_successes_required.clone(),
+        );
+
+        let load_balancer_failover_only_mode = Family::<LoadBalanceHealthLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_failover_only_mode",
+            "Whether watchdog is in failover-only mode",
+            load_balancer_failover_only_mode.clone(),
+        );
+
+        let load_balancer_last_route_drop_timestamp_seconds = Family::<LoadBalanceHealthLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_last_route_drop_timestamp_seconds",
+            "Unix timestamp of last route drop",
+            load_balancer_last_route_drop_timestamp_seconds.clone(),
+        );
+
+        let load_balancer_last_route_recover_timestamp_seconds = Family::<LoadBalanceHealthLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_last_route_recover_timestamp_seconds",
+            "Unix timestamp of last route recovery",
+            load_balancer_last_route_recover_timestamp_seconds.clone(),
+        );
+
         let load_balancer_route_drop_total = Family::<LoadBalanceHealthLabel, Gauge>::default();
         registry.register(
             "edgerouter_load_balancer_route_drop_total",
@@ -141,7 +189,55 @@ impl Collector for LoadBalanceStatusResult {
             load_balancer_ping_fail_total.clone(),
         );
 
+        let load_balancer_balance_local = Family::<LoadBalanceGroupLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_balance_local",
+            "Whether locally originated traffic is load balanced",
+            load_balancer_balance_local.clone(),
+        );
+
+        let load_balancer_lock_local_dns = Family::<LoadBalanceGroupLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_lock_local_dns",
+            "Whether local DNS traffic is locked to an interface",
+            load_balancer_lock_local_dns.clone(),
+        );
+
+        let load_balancer_conntrack_flush = Family::<LoadBalanceGroupLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_conntrack_flush",
+            "Whether connection tracking table is flushed on interface state change",
+            load_balancer_conntrack_flush.clone(),
+        );
+
+        let load_balancer_sticky_bits = Family::<LoadBalanceGroupLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_load_balancer_sticky_bits",
+            "Sticky bits of flows",
+            load_balancer_sticky_bits.clone(),
+        );
+
         for load_balance in self {
+            let labels = LoadBalanceGroupLabel {
+                group_name: load_balance.name.clone(),
+            };
+
+            load_balancer_balance_local
+                .get_or_create(&labels)
+                .set(load_balance.balance_local.into());
+
+            load_balancer_lock_local_dns
+                .get_or_create(&labels)
+                .set(load_balance.lock_local_dns.into());
+
+            load_balancer_conntrack_flush
+                .get_or_create(&labels)
+                .set(load_balance.conntrack_flush.into());
+
+            load_balancer_sticky_bits
+                .get_or_create(&labels)
+                .set(load_balance.sticky_bits.into());
+
             for interface in load_balance.interfaces {
                 let status = match interface.status {
                     LoadBalanceStatusStatus::Inactive | LoadBalanceStatusStatus::Unknown(_) => 0,
@@ -169,7 +265,7 @@ impl Collector for LoadBalanceStatusResult {
                 if let Some(watchdog) = interface.watchdog {
                     let (
                         health,
-                        (run_fails, _),
+                        (run_fails, run_fail_limit),
                         route_drops,
                     ) = match watchdog.status {
                         LoadBalanceWatchdogStatus::Ok | LoadBalanceWatchdogStatus::Running => (
@@ -213,10 +309,41 @@ impl Collector for LoadBalanceStatusResult {
                         .get_or_create(&labels)
                         .set(run_fails as i64);
 
+                    load_balancer_run_fail_limit
+                        .get_or_create(&labels)
+                        .set(run_fail_limit as i64);
+
+                    if let LoadBalanceWatchdogStatus::WaitOnRecovery(successes, successes_required) = watchdog.status {
+                        load_balancer_recovery_successes
+                            .get_or_create(&labels)
+                            .set(successes as i64);
+
+                        load_balancer_recovery_successes_required
+                            .get_or_create(&labels)
+                            .set(successes_required as i64);
+                    }
+
+                    load_balancer_failover_only_mode
+                        .get_or_create(&labels)
+                        .set(watchdog.failover_only_mode.into());
+
                     load_balancer_route_drop_total
                         .get_or_create(&labels)
                         .set(route_drops as i64);
 
+                    // Route changes are shown in the local time of the router.
+                    if let Some(last_route_drop) = watchdog.last_route_drop.and_then(|d| d.and_local_timezone(Local).earliest()) {
+                        load_balancer_last_route_drop_timestamp_seconds
+                            .get_or_create(&labels)
+                            .set(last_route_drop.timestamp());
+                    }
+
+                    if let Some(last_route_recover) = watchdog.last_route_recover.and_then(|r| r.and_local_timezone(Local).earliest()) {
+                        load_balancer_last_route_recover_timestamp_seconds
+                            .get_or_create(&labels)
+                            .set(last_route_recover.timestamp());
+                    }
+
                     let labels = labels.ping(ping_gateway);
                     load_balancer_ping_health
                         .get_or_create(&labels)
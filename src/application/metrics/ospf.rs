@@ -0,0 +1,160 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+
+use crate::{
+    application::metrics::{atomic, Collector, Gauge},
+    domain::ospf::OSPFNeighbor,
+    service::ospf::{OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult},
+};
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct OSPFAreaLabel {
+    area: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct OSPFInterfaceLabel {
+    interface_name: String,
+    area: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct OSPFNeighborLabel {
+    version: String,
+    neighbor_id: String,
+    address: String,
+    interface_name: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct OSPFNeighborStateLabel {
+    version: String,
+    neighbor_id: String,
+    address: String,
+    interface_name: String,
+    state: String,
+    role: String,
+}
+
+impl OSPFNeighborLabel {
+    pub fn new(version: &str, n: &OSPFNeighbor) -> Self {
+        let version = version.to_string();
+        let neighbor_id = n.neighbor_id.clone();
+        let address = n.address.map(|a| a.to_string()).unwrap_or_default();
+        let interface_name = n.interface.clone();
+        Self {
+            version,
+            neighbor_id,
+            address,
+            interface_name,
+        }
+    }
+
+    pub fn state(self, n: &OSPFNeighbor) -> OSPFNeighborStateLabel {
+        OSPFNeighborStateLabel {
+            version: self.version,
+            neighbor_id: self.neighbor_id,
+            address: self.address,
+            interface_name: self.interface_name,
+            state: n.state.clone(),
+            role: n.role.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl Collector for (OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult, OSPFNeighborResult) {
+    fn collect(self, registry: &mut Registry) {
+        let ospf_area_lsa = Family::<OSPFAreaLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ospf_area_lsa",
+            "Number of LSAs in OSPF area",
+            ospf_area_lsa.clone(),
+        );
+
+        let ospf_area_spf_runs_total = Family::<OSPFAreaLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ospf_area_spf_runs_total",
+            "Total number of SPF algorithm runs for OSPF area",
+            ospf_area_spf_runs_total.clone(),
+        );
+
+        let ospf_interface_cost = Family::<OSPFInterfaceLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ospf_interface_cost",
+            "Cost of OSPF interface",
+            ospf_interface_cost.clone(),
+        );
+
+        let ospf_neighbor_state = Family::<OSPFNeighborStateLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ospf_neighbor_state",
+            "State of OSPF neighbor",
+            ospf_neighbor_state.clone(),
+        );
+
+        let ospf_neighbor_up = Family::<OSPFNeighborLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_ospf_neighbor_up",
+            "Whether OSPF neighbor is in Full or 2-Way state",
+            ospf_neighbor_up.clone(),
+        );
+
+        let ospf_neighbor_dead_time_seconds = Family::<OSPFNeighborLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_ospf_neighbor_dead_time_seconds",
+            "Seconds remaining until OSPF neighbor is declared dead",
+            ospf_neighbor_dead_time_seconds.clone(),
+        );
+
+        let (areas, interfaces, neighbors, neighbors6) = self;
+        for area in areas {
+            let labels = OSPFAreaLabel {
+                area: area.area,
+            };
+
+            ospf_area_lsa
+                .get_or_create(&labels)
+                .set(area.lsas as i64);
+
+            ospf_area_spf_runs_total
+                .get_or_create(&labels)
+                .set(area.spf_runs as i64);
+        }
+
+        for interface in interfaces {
+            let labels = OSPFInterfaceLabel {
+                interface_name: interface.interface,
+                area: interface.area,
+            };
+
+            ospf_interface_cost
+                .get_or_create(&labels)
+                .set(interface.cost.into());
+        }
+
+        let neighbors = Iterator::chain(
+            neighbors.into_iter().map(|n| ("2", n)),
+            neighbors6.into_iter().map(|n| ("3", n)),
+        );
+        for (version, neighbor) in neighbors {
+            let labels = OSPFNeighborLabel::new(version, &neighbor);
+
+            ospf_neighbor_state
+                .get_or_create(&labels.clone().state(&neighbor))
+                .set(1);
+
+            ospf_neighbor_up
+                .get_or_create(&labels)
+                .set(matches!(neighbor.state.as_str(), "Full" | "2-Way").into());
+
+            if let Some(dead_time) = neighbor.dead_time {
+                ospf_neighbor_dead_time_seconds
+                    .get_or_create(&labels)
+                    .set(dead_time.as_secs_f64());
+            }
+        }
+    }
+}
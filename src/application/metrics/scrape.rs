@@ -0,0 +1,225 @@
+use std::{
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::Context;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+use tokio::time::{interval, timeout, MissedTickBehavior};
+
+use crate::{
+    application::metrics::{atomic, Collector, Gauge},
+    service::Runner,
+};
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct ScrapeCollectorLabel {
+    collector: String,
+}
+
+#[derive(Clone)]
+pub struct Scrape<T> {
+    collector: &'static str,
+    duration: Duration,
+    item: Option<T>,
+    last_success: Option<SystemTime>,
+}
+
+pub struct Scraper<R>
+where
+    R: Runner,
+{
+    collector: &'static str,
+    timeout: Option<Duration>,
+    source: Source<R>,
+    last_success: Arc<Mutex<Option<SystemTime>>>,
+}
+
+enum Source<R>
+where
+    R: Runner,
+{
+    Live(R),
+    Polled(Arc<RwLock<Option<Scrape<R::Item>>>>),
+}
+
+pub struct ScrapeCollector {
+    scrape_collector_success: Family<ScrapeCollectorLabel, Gauge>,
+    scrape_collector_duration_seconds: Family<ScrapeCollectorLabel, Gauge<f64, atomic::AtomicU64>>,
+    collector_last_success_timestamp_seconds: Family<ScrapeCollectorLabel, Gauge<f64, atomic::AtomicU64>>,
+}
+
+impl<T> Scrape<T> {
+    async fn run<R>(
+        collector: &'static str,
+        runner: &R,
+        duration: Option<Duration>,
+        last_success: &Mutex<Option<SystemTime>>,
+    ) -> Self
+    where
+        R: Runner<Item = T>,
+    {
+        let start = Instant::now();
+        let result = match duration {
+            Some(duration) => timeout(duration, runner.run())
+                .await
+                .with_context(|| format!("timed out collecting {collector} metrics after {duration:?}"))
+                .flatten(),
+            None => runner.run().await,
+        };
+        let duration = start.elapsed();
+
+        let mut last_success = last_success.lock().unwrap();
+        let item = match result {
+            Ok(item) => {
+                *last_success = Some(SystemTime::now());
+                Some(item)
+            },
+            Err(e) => {
+                log::error!("failed to collect {collector} metrics\nError: {e:?}");
+                None
+            },
+        };
+
+        Self {
+            collector,
+            duration,
+            item,
+            last_success: *last_success,
+        }
+    }
+}
+
+impl<R> Scraper<R>
+where
+    R: Runner + Send + Sync + 'static,
+    R::Item: Clone + Send + Sync + 'static,
+{
+    pub fn new(collector: &'static str, runner: R, timeout: Option<Duration>, poll_interval: Option<Duration>) -> Self {
+        let last_success = Arc::new(Mutex::new(None));
+        let source = match poll_interval {
+            Some(poll_interval) => Source::Polled(Self::poll(collector, runner, timeout, poll_interval, last_success.clone())),
+            None => Source::Live(runner),
+        };
+
+        Self {
+            collector,
+            timeout,
+            source,
+            last_success,
+        }
+    }
+
+    fn poll(
+        collector: &'static str,
+        runner: R,
+        timeout: Option<Duration>,
+        poll_interval: Duration,
+        last_success: Arc<Mutex<Option<SystemTime>>>,
+    ) -> Arc<RwLock<Option<Scrape<R::Item>>>> {
+        let snapshot = Arc::new(RwLock::new(None));
+
+        let latest = snapshot.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(poll_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+
+                let scrape = Scrape::run(collector, &runner, timeout, &last_success).await;
+                *latest.write().unwrap() = Some(scrape);
+            }
+        });
+
+        snapshot
+    }
+
+    pub async fn scrape(&self) -> Option<Scrape<R::Item>> {
+        match &self.source {
+            Source::Live(runner) => Some(Scrape::run(self.collector, runner, self.timeout, &self.last_success).await),
+            Source::Polled(snapshot) => snapshot.read().unwrap().clone(),
+        }
+    }
+}
+
+pub async fn scrape<R>(scraper: Option<&Scraper<R>>, collectors: &[&str]) -> Option<Scrape<R::Item>>
+where
+    R: Runner + Send + Sync + 'static,
+    R::Item: Clone + Send + Sync + 'static,
+{
+    match scraper {
+        Some(scraper) if collectors.is_empty() || collectors.contains(&scraper.collector) => scraper.scrape().await,
+        _ => None,
+    }
+}
+
+impl ScrapeCollector {
+    pub fn new(registry: &mut Registry) -> Self {
+        let scrape_collector_success = Family::<ScrapeCollectorLabel, Gauge>::default();
+        registry.register(
+            "edgerouter_scrape_collector_success",
+            "Whether a collector succeeded",
+            scrape_collector_success.clone(),
+        );
+
+        let scrape_collector_duration_seconds = Family::<ScrapeCollectorLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_scrape_collector_duration_seconds",
+            "Duration of a collector scrape",
+            scrape_collector_duration_seconds.clone(),
+        );
+
+        let collector_last_success_timestamp_seconds = Family::<ScrapeCollectorLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_collector_last_success_timestamp_seconds",
+            "Unix timestamp of the last successful collector run",
+            collector_last_success_timestamp_seconds.clone(),
+        );
+
+        Self {
+            scrape_collector_success,
+            scrape_collector_duration_seconds,
+            collector_last_success_timestamp_seconds,
+        }
+    }
+
+    pub fn collect<T>(&self, scrape: Option<Scrape<T>>, registry: &mut Registry)
+    where
+        T: Collector,
+    {
+        let Some(scrape) = scrape else {
+            return;
+        };
+
+        let labels = ScrapeCollectorLabel {
+            collector: scrape.collector.to_string(),
+        };
+
+        let success = match scrape.item {
+            Some(item) => {
+                item.collect(registry);
+                1
+            },
+            None => 0,
+        };
+
+        self.scrape_collector_success
+            .get_or_create(&labels)
+            .set(success);
+
+        self.scrape_collector_duration_seconds
+            .get_or_create(&labels)
+            .set(scrape.duration.as_secs_f64());
+
+        if let Some(timestamp) = scrape.last_success.and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok()) {
+            self.collector_last_success_timestamp_seconds
+                .get_or_create(&labels)
+                .set(timestamp.as_secs_f64());
+        }
+    }
+}
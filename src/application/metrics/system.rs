@@ -0,0 +1,181 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::family::Family,
+    registry::Registry,
+};
+
+use crate::{
+    application::metrics::{atomic, Collector, Gauge},
+    service::system::SystemResult,
+};
+
+const USER_HZ: f64 = 100_f64;
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct CpuLabel {
+    cpu: String,
+    mode: String,
+}
+
+#[derive(Clone, Debug, EncodeLabelSet, Eq, Hash, PartialEq)]
+pub struct SensorLabel {
+    sensor: String,
+}
+
+impl Collector for SystemResult {
+    fn collect(self, registry: &mut Registry) {
+        let uptime_seconds = Gauge::<f64, atomic::AtomicU64>::default();
+        registry.register(
+            "edgerouter_system_uptime_seconds",
+            "Time since the system booted",
+            uptime_seconds.clone(),
+        );
+
+        let load1 = Gauge::<f64, atomic::AtomicU64>::default();
+        registry.register(
+            "edgerouter_system_load1",
+            "1 minute load average",
+            load1.clone(),
+        );
+
+        let load5 = Gauge::<f64, atomic::AtomicU64>::default();
+        registry.register(
+            "edgerouter_system_load5",
+            "5 minute load average",
+            load5.clone(),
+        );
+
+        let load15 = Gauge::<f64, atomic::AtomicU64>::default();
+        registry.register(
+            "edgerouter_system_load15",
+            "15 minute load average",
+            load15.clone(),
+        );
+
+        let cpu_seconds_total = Family::<CpuLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_system_cpu_seconds_total",
+            "Total seconds the CPU spent in each mode",
+            cpu_seconds_total.clone(),
+        );
+
+        let memory_total_bytes = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_system_memory_total_bytes",
+            "Total memory",
+            memory_total_bytes.clone(),
+        );
+
+        let memory_free_bytes = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_system_memory_free_bytes",
+            "Free memory",
+            memory_free_bytes.clone(),
+        );
+
+        let memory_buffers_bytes = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_system_memory_buffers_bytes",
+            "Memory used for buffers",
+            memory_buffers_bytes.clone(),
+        );
+
+        let memory_cached_bytes = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_system_memory_cached_bytes",
+            "Memory used for page cache",
+            memory_cached_bytes.clone(),
+        );
+
+        let swap_total_bytes = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_system_swap_total_bytes",
+            "Total swap",
+            swap_total_bytes.clone(),
+        );
+
+        let swap_free_bytes = Gauge::<i64>::default();
+        registry.register(
+            "edgerouter_system_swap_free_bytes",
+            "Free swap",
+            swap_free_bytes.clone(),
+        );
+
+        let temperature_celsius = Family::<SensorLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_system_temperature_celsius",
+            "Temperature reported by hardware sensor",
+            temperature_celsius.clone(),
+        );
+
+        let fan_speed_rpm = Family::<SensorLabel, Gauge<f64, atomic::AtomicU64>>::default();
+        registry.register(
+            "edgerouter_system_fan_speed_rpm",
+            "Fan speed reported by hardware sensor",
+            fan_speed_rpm.clone(),
+        );
+
+        uptime_seconds.set(self.uptime.as_secs_f64());
+
+        load1.set(self.load_average.one);
+        load5.set(self.load_average.five);
+        load15.set(self.load_average.fifteen);
+
+        for cpu in self.cpus {
+            let modes = [
+                ("user", cpu.user),
+                ("nice", cpu.nice),
+                ("system", cpu.system),
+                ("idle", cpu.idle),
+                ("iowait", cpu.iowait),
+                ("irq", cpu.irq),
+                ("softirq", cpu.softirq),
+                ("steal", cpu.steal),
+            ];
+            for (mode, ticks) in modes {
+                let labels = CpuLabel {
+                    cpu: cpu.cpu.clone(),
+                    mode: mode.to_string(),
+                };
+                cpu_seconds_total
+                    .get_or_create(&labels)
+                    .set(ticks as f64 / USER_HZ);
+            }
+        }
+
+        let memory = self.memory;
+        memory_total_bytes.set(memory.total as i64);
+        memory_free_bytes.set(memory.free as i64);
+        if let Some(available) = memory.available {
+            let memory_available_bytes = Gauge::<i64>::default();
+            registry.register(
+                "edgerouter_system_memory_available_bytes",
+                "Memory available for starting new applications",
+                memory_available_bytes.clone(),
+            );
+            memory_available_bytes.set(available as i64);
+        }
+        memory_buffers_bytes.set(memory.buffers as i64);
+        memory_cached_bytes.set(memory.cached as i64);
+        swap_total_bytes.set(memory.swap_total as i64);
+        swap_free_bytes.set(memory.swap_free as i64);
+
+        for sensor in self.hardware.temperatures {
+            let labels = SensorLabel {
+                sensor: sensor.name,
+            };
+            temperature_celsius
+                .get_or_create(&labels)
+                .set(sensor.value);
+        }
+
+        for sensor in self.hardware.fans {
+            let labels = SensorLabel {
+                sensor: sensor.name,
+            };
+            fan_speed_rpm
+                .get_or_create(&labels)
+                .set(sensor.value);
+        }
+    }
+}
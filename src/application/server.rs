@@ -21,7 +21,7 @@ use {
 use crate::application::metrics;
 
 pub trait Controller<T> {
-    fn handle(&self) -> impl Future<Output = anyhow::Result<T>> + Send;
+    fn handle(&self, query: &[(String, String)]) -> impl Future<Output = anyhow::Result<T>> + Send;
 }
 
 pub struct Engine<MetricsController> {
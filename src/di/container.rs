@@ -1,26 +1,58 @@
 use crate::{
-    application::{metrics::MetricsHandler, server::Engine},
+    application::{
+        metrics::{scrape::Scraper, MetricsHandler},
+        server::Engine,
+    },
     infrastructure::{
-        client::runner::ipsec::IPsecRunner,
+        client::runner::{ddns::DdnsRecordRunner, interface::NetlinkInterfaceRunner, ipsec::ViciIPsecRunner},
         cmd::{
             parser::{
-                bgp::BGPParser,
+                bgp::{BGPNeighborDetailParser, BGPParser},
+                bgp_json::{BGPJsonParser, BGPNeighborDetailJsonParser},
                 ddns::DdnsParser,
+                dhcp::{DhcpLeaseParser, DhcpStatisticsParser},
+                firewall::{FirewallParser, NatParser},
+                hardware::HardwareParser,
                 interface::InterfaceParser,
+                ipsec::IPsecParser,
                 load_balance::{LoadBalanceStatusParser, LoadBalanceWatchdogParser},
+                ospf::{OSPF6NeighborParser, OSPFAreaParser, OSPFInterfaceParser, OSPFNeighborParser},
                 pppoe::PPPoEParser,
                 version::VersionParser,
             },
             runner::{
                 bgp::BGPRunner,
                 ddns::DdnsRunner,
+                dhcp::DhcpRunner,
+                firewall::FirewallRunner,
+                hardware::HardwareRunner,
+                interface::InterfaceRunner,
+                ipsec::IPsecRunner,
                 load_balance::LoadBalanceRunner,
+                ospf::OSPFRunner,
                 pppoe::PPPoERunner,
                 version::VersionRunner,
                 CommandExecutor,
             },
         },
         config::env,
+        fs::{
+            parser::{
+                conntrack::ConntrackParser,
+                conntrack_stat::ConntrackStatParser,
+                load_average::LoadAverageParser,
+                meminfo::MeminfoParser,
+                net_dev::NetDevParser,
+                stat::StatParser,
+                uptime::UptimeParser,
+            },
+            runner::{
+                conntrack::ConntrackRunner,
+                interface::InterfaceStatisticsRunner,
+                system::SystemRunner,
+                FileReader,
+            },
+        },
     },
 };
 
@@ -29,18 +61,122 @@ pub struct Application;
 impl Application {
     pub async fn start() -> anyhow::Result<()> {
         let config = env::init();
+        let executor = CommandExecutor::new(config.command_timeout);
+        let timeout = config.collector_timeout;
+        let metrics_handler = MetricsHandler::new(
+            config.collector_bgp.then(|| Scraper::new(
+                "bgp",
+                BGPRunner::new(
+                    config.vtysh_command.clone(),
+                    config.bgp_format,
+                    executor,
+                    BGPParser,
+                    BGPNeighborDetailParser,
+                    BGPJsonParser,
+                    BGPNeighborDetailJsonParser,
+                    VersionRunner::new(config.op_command.clone(), executor, VersionParser),
+                ),
+                timeout,
+                config.poll_interval("bgp"),
+            )),
+            config.collector_conntrack.then(|| Scraper::new(
+                "conntrack",
+                ConntrackRunner::new(
+                    config.procfs_path.clone(),
+                    config.collector_conntrack_entries,
+                    FileReader,
+                    ConntrackStatParser,
+                    ConntrackParser,
+                ),
+                timeout,
+                config.poll_interval("conntrack"),
+            )),
+            config.collector_ddns.then(|| Scraper::new(
+                "ddns",
+                DdnsRecordRunner::new(config.ddns_resolver, DdnsRunner::new(config.op_ddns_command.clone(), executor, DdnsParser)),
+                timeout,
+                config.poll_interval("ddns"),
+            )),
+            config.collector_dhcp.then(|| Scraper::new(
+                "dhcp",
+                DhcpRunner::new(config.op_command.clone(), config.collector_dhcp_leases, executor, DhcpStatisticsParser, DhcpLeaseParser),
+                timeout,
+                config.poll_interval("dhcp"),
+            )),
+            config.collector_firewall.then(|| Scraper::new(
+                "firewall",
+                FirewallRunner::new(config.op_command.clone(), executor, FirewallParser, NatParser),
+                timeout,
+                config.poll_interval("firewall"),
+            )),
+            config.collector_interface.then(|| Scraper::new(
+                "interface",
+                InterfaceStatisticsRunner::new(
+                    config.procfs_path.clone(),
+                    config.sysfs_path.clone(),
+                    NetlinkInterfaceRunner::new(InterfaceRunner::new(config.ip_command.clone(), executor, InterfaceParser)),
+                    FileReader,
+                    NetDevParser,
+                ),
+                timeout,
+                config.poll_interval("interface"),
+            )),
+            config.collector_ipsec.then(|| Scraper::new(
+                "ipsec",
+                ViciIPsecRunner::new(config.vici_path.clone(), IPsecRunner::new(config.ipsec_command.clone(), executor, IPsecParser)),
+                timeout,
+                config.poll_interval("ipsec"),
+            )),
+            config.collector_load_balance.then(|| Scraper::new(
+                "load_balance",
+                LoadBalanceRunner::new(config.op_command.clone(), executor, LoadBalanceStatusParser, LoadBalanceWatchdogParser),
+                timeout,
+                config.poll_interval("load_balance"),
+            )),
+            config.collector_ospf.then(|| Scraper::new(
+                "ospf",
+                OSPFRunner::new(config.vtysh_command.clone(), executor, OSPFAreaParser, OSPFInterfaceParser, OSPFNeighborParser, OSPF6NeighborParser),
+                timeout,
+                config.poll_interval("ospf"),
+            )),
+            config.collector_pppoe.then(|| Scraper::new(
+                "pppoe",
+                PPPoERunner::new(
+                    config.op_command.clone(),
+                    executor,
+                    PPPoEParser,
+                    NetlinkInterfaceRunner::new(InterfaceRunner::new(config.ip_command.clone(), executor, InterfaceParser)),
+                ),
+                timeout,
+                config.poll_interval("pppoe"),
+            )),
+            config.collector_system.then(|| Scraper::new(
+                "system",
+                SystemRunner::new(
+                    config.procfs_path.clone(),
+                    HardwareRunner::new(config.op_command.clone(), executor, HardwareParser),
+                    FileReader,
+                    UptimeParser,
+                    LoadAverageParser,
+                    StatParser,
+                    MeminfoParser,
+                ),
+                timeout,
+                config.poll_interval("system"),
+            )),
+            config.collector_version.then(|| Scraper::new(
+                "version",
+                VersionRunner::new(config.op_command.clone(), executor, VersionParser),
+                timeout,
+                config.poll_interval("version"),
+            )),
+        );
+
         let engine = Engine::new(
             config.port,
             config.tls_cert,
             config.tls_key,
-            MetricsHandler::new(
-                BGPRunner::new(config.vtysh_command, CommandExecutor, BGPParser),
-                DdnsRunner::new(config.op_ddns_command, CommandExecutor, DdnsParser),
-                IPsecRunner::new(config.vici_path),
-                LoadBalanceRunner::new(config.op_command.clone(), CommandExecutor, LoadBalanceStatusParser, LoadBalanceWatchdogParser),
-                PPPoERunner::new(config.op_command.clone(), config.ip_command, CommandExecutor, PPPoEParser, InterfaceParser),
-                VersionRunner::new(config.op_command, CommandExecutor, VersionParser),
-            ),
+            metrics_handler,
         );
 
         engine.start().await
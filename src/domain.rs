@@ -3,11 +3,16 @@ use std::fmt::Debug;
 use number_prefix::{NumberPrefix, Prefix};
 
 pub mod bgp;
+pub mod conntrack;
 pub mod ddns;
+pub mod dhcp;
+pub mod firewall;
 pub mod interface;
 pub mod ipsec;
 pub mod load_balance;
+pub mod ospf;
 pub mod pppoe;
+pub mod system;
 pub mod version;
 
 const NUM_1024: f64 = 1024_f64;
@@ -1,24 +1,20 @@
-use std::{
-    iter::{Chain, Flatten},
-    net::IpAddr,
-    option::IntoIter,
-    time::Duration,
-};
+use std::{net::IpAddr, time::Duration};
 
-use derive_more::{Deref, DerefMut, IntoIterator};
-
-type IntoBGPNeighbor = IntoIter<Vec<BGPNeighbor>>;
-
-#[derive(Deref, DerefMut, IntoIterator)]
-pub struct BGPIterator(Chain<Flatten<IntoBGPNeighbor>, Flatten<IntoBGPNeighbor>>);
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BGPTable {
+    pub vrf: String,
+    pub afi: String,
+    pub safi: String,
+    pub status: BGPStatus,
+}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BGPStatus {
     pub router_id: String,
     pub local_as: u32,
     pub table_version: u32,
-    pub as_paths: u64,
-    pub communities: u64,
+    pub as_paths: Option<u64>,
+    pub communities: Option<u64>,
     pub ebgp_maximum_paths: Option<u64>,
     pub ibgp_maximum_paths: Option<u64>,
     pub neighbors: Vec<BGPNeighbor>,
@@ -38,239 +34,35 @@ pub struct BGPNeighbor {
     pub uptime: Option<Duration>,
     pub state: Option<String>,
     pub prefixes_received: Option<u64>,
+    pub detail: Option<BGPNeighborDetail>,
 }
 
-impl From<(Option<BGPStatus>, Option<BGPStatus>)> for BGPIterator {
-    fn from((bgp4, bgp6): (Option<BGPStatus>, Option<BGPStatus>)) -> Self {
-        Self(
-            Iterator::chain(
-                bgp4.map(|s| s.neighbors).into_iter().flatten(),
-                bgp6.map(|s| s.neighbors).into_iter().flatten(),
-            )
-        )
-    }
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BGPNeighborDetail {
+    pub neighbor: IpAddr,
+    pub description: Option<String>,
+    pub connections_established: Option<u64>,
+    pub connections_dropped: Option<u64>,
+    pub last_reset_reason: Option<String>,
+    pub hold_time: Option<Duration>,
+    pub keepalive_interval: Option<Duration>,
+    pub messages_sent: BGPMessageCounters,
+    pub messages_received: BGPMessageCounters,
+    pub address_families: Vec<BGPNeighborAddressFamily>,
 }
 
-#[cfg(test)]
-mod tests {
-    use std::net::{Ipv4Addr, Ipv6Addr};
-
-    use pretty_assertions::assert_eq;
-
-    use super::*;
-
-    #[test]
-    fn iterate_empty() {
-        let status = (None, None);
-
-        let mut iterator = BGPIterator::from(status);
-        assert_eq!(iterator.next(), None);
-    }
-
-    #[test]
-    fn iterate_all() {
-        let status = (
-            Some(BGPStatus {
-                router_id: "192.0.2.1".to_string(),
-                local_as: 64496,
-                table_version: 128,
-                as_paths: 1,
-                communities: 2,
-                ebgp_maximum_paths: Some(8),
-                ibgp_maximum_paths: Some(4),
-                neighbors: vec![
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 1000,
-                        messages_sent: 5000,
-                        table_version: 128,
-                        in_queue: 1,
-                        out_queue: 5,
-                        uptime: Some(Duration::new(4271, 0)),
-                        state: None,
-                        prefixes_received: Some(9),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 2000,
-                        messages_sent: 6000,
-                        table_version: 128,
-                        in_queue: 2,
-                        out_queue: 6,
-                        uptime: Some(Duration::new(93780, 0)),
-                        state: None,
-                        prefixes_received: Some(10),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 0,
-                        messages_sent: 0,
-                        table_version: 0,
-                        in_queue: 0,
-                        out_queue: 0,
-                        uptime: None,
-                        state: Some("Connect".to_string()),
-                        prefixes_received: None,
-                    },
-                ],
-                sessions: 3,
-            }),
-            Some(BGPStatus {
-                router_id: "192.0.2.1".to_string(),
-                local_as: 64496,
-                table_version: 128,
-                as_paths: 1,
-                communities: 2,
-                ebgp_maximum_paths: Some(8),
-                ibgp_maximum_paths: Some(4),
-                neighbors: vec![
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 3000,
-                        messages_sent: 7000,
-                        table_version: 128,
-                        in_queue: 3,
-                        out_queue: 7,
-                        uptime: Some(Duration::new(12813, 0)),
-                        state: None,
-                        prefixes_received: Some(0),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 4000,
-                        messages_sent: 8000,
-                        table_version: 128,
-                        in_queue: 4,
-                        out_queue: 8,
-                        uptime: Some(Duration::new(363960, 0)),
-                        state: None,
-                        prefixes_received: Some(0),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x4)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 0,
-                        messages_sent: 0,
-                        table_version: 0,
-                        in_queue: 0,
-                        out_queue: 0,
-                        uptime: None,
-                        state: Some("Connect".to_string()),
-                        prefixes_received: None,
-                    },
-                ],
-                sessions: 4,
-            }),
-        );
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BGPMessageCounters {
+    pub open: Option<u64>,
+    pub update: Option<u64>,
+    pub notification: Option<u64>,
+    pub keepalive: Option<u64>,
+    pub route_refresh: Option<u64>,
+}
 
-        let mut iterator = BGPIterator::from(status);
-        assert_eq!(
-            iterator.next(),
-            Some(BGPNeighbor {
-                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
-                version: 4,
-                remote_as: 64497,
-                messages_received: 1000,
-                messages_sent: 5000,
-                table_version: 128,
-                in_queue: 1,
-                out_queue: 5,
-                uptime: Some(Duration::new(4271, 0)),
-                state: None,
-                prefixes_received: Some(9),
-            }),
-        );
-        assert_eq!(
-            iterator.next(),
-            Some(BGPNeighbor {
-                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
-                version: 4,
-                remote_as: 64497,
-                messages_received: 2000,
-                messages_sent: 6000,
-                table_version: 128,
-                in_queue: 2,
-                out_queue: 6,
-                uptime: Some(Duration::new(93780, 0)),
-                state: None,
-                prefixes_received: Some(10),
-            }),
-        );
-        assert_eq!(
-            iterator.next(),
-            Some(BGPNeighbor {
-                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
-                version: 4,
-                remote_as: 64497,
-                messages_received: 0,
-                messages_sent: 0,
-                table_version: 0,
-                in_queue: 0,
-                out_queue: 0,
-                uptime: None,
-                state: Some("Connect".to_string()),
-                prefixes_received: None,
-            }),
-        );
-        assert_eq!(
-            iterator.next(),
-            Some(BGPNeighbor {
-                neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
-                version: 4,
-                remote_as: 64497,
-                messages_received: 3000,
-                messages_sent: 7000,
-                table_version: 128,
-                in_queue: 3,
-                out_queue: 7,
-                uptime: Some(Duration::new(12813, 0)),
-                state: None,
-                prefixes_received: Some(0),
-            }),
-        );
-        assert_eq!(
-            iterator.next(),
-            Some(BGPNeighbor {
-                neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
-                version: 4,
-                remote_as: 64497,
-                messages_received: 4000,
-                messages_sent: 8000,
-                table_version: 128,
-                in_queue: 4,
-                out_queue: 8,
-                uptime: Some(Duration::new(363960, 0)),
-                state: None,
-                prefixes_received: Some(0),
-            }),
-        );
-        assert_eq!(
-            iterator.next(),
-            Some(BGPNeighbor {
-                neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x4)),
-                version: 4,
-                remote_as: 64497,
-                messages_received: 0,
-                messages_sent: 0,
-                table_version: 0,
-                in_queue: 0,
-                out_queue: 0,
-                uptime: None,
-                state: Some("Connect".to_string()),
-                prefixes_received: None,
-            }),
-        );
-        assert_eq!(iterator.next(), None);
-    }
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BGPNeighborAddressFamily {
+    pub address_family: String,
+    pub prefixes_accepted: Option<u64>,
+    pub prefixes_advertised: Option<u64>,
 }
@@ -0,0 +1,22 @@
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Conntrack {
+    pub count: u64,
+    pub max: u64,
+    pub statistics: Vec<ConntrackStatistics>,
+    pub entries: Option<Vec<ConntrackEntry>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConntrackStatistics {
+    pub cpu: usize,
+    pub insert_failed: u64,
+    pub drop: u64,
+    pub early_drop: u64,
+    pub search_restart: Option<u64>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConntrackEntry {
+    pub protocol: String,
+    pub state: Option<String>,
+}
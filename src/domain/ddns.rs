@@ -1,6 +1,7 @@
 use std::net::IpAddr;
 
 use chrono::NaiveDateTime;
+use derive_more::Display;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DdnsStatus {
@@ -12,29 +13,61 @@ pub struct DdnsStatus {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DdnsRecord {
+    pub interface: String,
+    pub host_name: String,
+    pub ip_address: IpAddr,
+    pub addresses: Vec<IpAddr>,
+}
+
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
 pub enum DdnsUpdateStatus {
+    #[display("abuse")]
     Abuse,
+    #[display("badagent")]
     BadAgent,
+    #[display("badauth")]
     BadAuth,
+    #[display("badsys")]
     BadSystemParameter,
+    #[display("blocked")]
     Blocked,
+    #[display("dnserr")]
     DNSError,
+    #[display("failed")]
     Failed,
+    #[display("good")]
     Good,
+    #[display("illegal")]
     Illegal,
+    #[display("noaccess")]
     NoAccess,
+    #[display("nochg")]
     NoChange,
+    #[display("noconnect")]
     NoConnect,
+    #[display("noerror")]
     NoError,
+    #[display("notfqdn")]
     NoFQDN,
+    #[display("nohost")]
     NoHost,
+    #[display("noservice")]
     NoService,
+    #[display("!active")]
     NotActive,
+    #[display("!donator")]
     NotDonator,
+    #[display("notdyn")]
     NotDynamicHost,
+    #[display("!yours")]
     NotYours,
+    #[display("numhost")]
     NumHost,
+    #[display("toosoon")]
     TooSoon,
+    #[display("unauth")]
     Unauthenticated,
+    #[display("{_0}")]
     Unknown(String),
 }
@@ -0,0 +1,20 @@
+use std::net::IpAddr;
+
+use chrono::NaiveDateTime;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DhcpPool {
+    pub pool: String,
+    pub pool_size: u64,
+    pub leased: u64,
+    pub available: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DhcpLease {
+    pub ip_address: IpAddr,
+    pub mac_address: String,
+    pub expiration: Option<NaiveDateTime>,
+    pub pool: String,
+    pub client_name: Option<String>,
+}
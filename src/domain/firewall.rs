@@ -0,0 +1,28 @@
+use crate::domain::pppoe::{ByteSize, PacketSize};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FirewallRuleset {
+    pub ip_version: String,
+    pub name: String,
+    pub rules: Vec<FirewallRule>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FirewallRule {
+    pub rule: u32,
+    pub packets: PacketSize,
+    pub bytes: ByteSize,
+    pub action: String,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NatRule {
+    pub rule: u32,
+    pub packets: PacketSize,
+    pub bytes: ByteSize,
+    pub nat_type: String,
+    pub inbound_interface: Option<String>,
+    pub outbound_interface: Option<String>,
+    pub description: Option<String>,
+}
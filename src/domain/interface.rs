@@ -13,3 +13,26 @@ pub struct AddrInfo {
     pub address: Option<IpAddr>,
     pub prefixlen: u32,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InterfaceStatistics {
+    pub interface: Interface,
+    pub mtu: Option<u32>,
+    pub speed: Option<u64>,
+    pub carrier_changes: Option<u64>,
+    pub counters: InterfaceCounters,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InterfaceCounters {
+    pub ifname: String,
+    pub receive_bytes: u64,
+    pub receive_packets: u64,
+    pub receive_errors: u64,
+    pub receive_dropped: u64,
+    pub receive_multicast: u64,
+    pub transmit_bytes: u64,
+    pub transmit_packets: u64,
+    pub transmit_errors: u64,
+    pub transmit_dropped: u64,
+}
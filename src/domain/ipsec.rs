@@ -1,8 +1,11 @@
+use std::time::SystemTime;
+
+use derive_more::Display;
 use indexmap::IndexMap;
 use serde::Deserialize;
 
 // See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/vici/vici_query.c#L378-L498
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub struct SA {
     pub uniqueid: u32,
@@ -30,7 +33,7 @@ pub struct SA {
 }
 
 // See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/sa/ike_sa.h#L287-L365
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SAState {
     Created,
@@ -46,7 +49,7 @@ pub enum SAState {
 }
 
 // See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/vici/vici_query.c#L262-L310
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub struct ChildSA {
     pub name: String,
@@ -76,7 +79,7 @@ pub struct ChildSA {
 }
 
 // See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/sa/child_sa.h#L37-L96
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ChildSAState {
     Created,
@@ -92,3 +95,112 @@ pub enum ChildSAState {
     #[serde(other)]
     Unknown,
 }
+
+// See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/vici/vici_config.c
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Conn {
+    pub local_addrs: Vec<String>,
+    pub remote_addrs: Vec<String>,
+    pub version: String,
+    pub children: IndexMap<String, ChildConn>,
+}
+
+// See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/vici/vici_config.c
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ChildConn {
+    pub mode: String,
+    pub local_ts: Vec<String>,
+    pub remote_ts: Vec<String>,
+}
+
+// See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/vici/vici_query.c
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Stats {
+    pub workers: StatsWorkers,
+    pub queues: StatsPriorities,
+    pub scheduled: u64,
+    pub ikesas: StatsIKESAs,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct StatsWorkers {
+    pub total: u64,
+    pub idle: u64,
+    pub active: StatsPriorities,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct StatsPriorities {
+    pub critical: u64,
+    pub high: u64,
+    pub medium: u64,
+    pub low: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct StatsIKESAs {
+    pub total: u64,
+    pub half_open: u64,
+}
+
+// See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/counters/counters_query.h
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Counters {
+    pub counters: IndexMap<String, IndexMap<String, u64>>,
+}
+
+// See https://github.com/strongswan/strongswan/blob/5.9.5/src/libcharon/plugins/vici/README.md#server-issued-events
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Event {
+    pub up: Option<String>,
+    #[serde(flatten)]
+    pub sas: IndexMap<String, EventSA>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct EventSA {
+    #[serde(default)]
+    pub child_sas: IndexMap<String, EventChildSA>,
+}
+
+// Rekey events contain the old and new child SAs instead of the child SA itself.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventChildSA {
+    pub name: Option<String>,
+    pub old: Option<Box<EventChildSA>>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TunnelEvents {
+    pub ike_sa: String,
+    pub tunnel: String,
+    pub up: u64,
+    pub down: u64,
+    pub rekey: u64,
+    pub last_change: Option<SystemTime>,
+}
+
+impl StatsPriorities {
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        [("critical", self.critical), ("high", self.high), ("medium", self.medium), ("low", self.low)].into_iter()
+    }
+}
+
+impl EventChildSA {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref().or_else(|| self.old.as_ref()?.name())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Backend {
+    #[display("vici")]
+    Vici,
+    #[display("command")]
+    Command,
+}
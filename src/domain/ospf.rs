@@ -0,0 +1,27 @@
+use std::{net::IpAddr, time::Duration};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OSPFArea {
+    pub area: String,
+    pub lsas: u64,
+    pub spf_runs: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OSPFInterface {
+    pub interface: String,
+    pub area: String,
+    pub cost: u32,
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OSPFNeighbor {
+    pub neighbor_id: String,
+    pub priority: u32,
+    pub state: String,
+    pub role: Option<String>,
+    pub dead_time: Option<Duration>,
+    pub address: Option<IpAddr>,
+    pub interface: String,
+}
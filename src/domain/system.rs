@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct System {
+    pub uptime: Duration,
+    pub load_average: LoadAverage,
+    pub cpus: Vec<CpuTime>,
+    pub memory: Memory,
+    pub hardware: Hardware,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpuTime {
+    pub cpu: String,
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Memory {
+    pub total: u64,
+    pub free: u64,
+    pub available: Option<u64>,
+    pub buffers: u64,
+    pub cached: u64,
+    pub swap_total: u64,
+    pub swap_free: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hardware {
+    pub temperatures: Vec<Sensor>,
+    pub fans: Vec<Sensor>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sensor {
+    pub name: String,
+    pub value: f64,
+}
@@ -1 +1,3 @@
+pub mod ddns;
+pub mod interface;
 pub mod ipsec;
@@ -0,0 +1,274 @@
+use std::net::{IpAddr, SocketAddr};
+
+use futures::future::join_all;
+use hickory_resolver::{
+    config::{NameServerConfigGroup, ResolverConfig},
+    name_server::TokioConnectionProvider,
+    proto::rr::{RData, RecordType},
+    TokioResolver,
+};
+
+use crate::{
+    domain::ddns::{DdnsRecord, DdnsStatus},
+    service::{
+        ddns::{DdnsRecordResult, DdnsStatusResult},
+        Runner,
+    },
+};
+
+pub struct DdnsRecordRunner<R> {
+    resolver: Option<TokioResolver>,
+    runner: R,
+}
+
+impl<R> DdnsRecordRunner<R>
+where
+    R: Runner<Item = DdnsStatusResult> + Send + Sync,
+{
+    pub fn new(resolver: Option<SocketAddr>, runner: R) -> Self {
+        let resolver = resolver.map(|addr| {
+            let name_servers = NameServerConfigGroup::from_ips_clear(&[addr.ip()], addr.port(), true);
+            let mut builder = TokioResolver::builder_with_config(
+                ResolverConfig::from_parts(None, vec![], name_servers),
+                TokioConnectionProvider::default(),
+            );
+            // Records are resolved on each scrape to catch changes at the provider.
+            builder.options_mut().cache_size = 0;
+            builder.build()
+        });
+
+        Self {
+            resolver,
+            runner,
+        }
+    }
+
+    async fn records(resolver: &TokioResolver, statuses: &DdnsStatusResult) -> DdnsRecordResult {
+        let records = statuses.iter().map(|status| Self::record(resolver, status));
+        join_all(records).await.into_iter().flatten().collect()
+    }
+
+    async fn record(resolver: &TokioResolver, status: &DdnsStatus) -> Option<DdnsRecord> {
+        let host_name = status.host_name.clone()?;
+        let ip_address = status.ip_address?;
+        let record_type = match ip_address {
+            IpAddr::V4(_) => RecordType::A,
+            IpAddr::V6(_) => RecordType::AAAA,
+        };
+
+        let addresses = match resolver.lookup(host_name.as_str(), record_type).await {
+            Ok(lookup) => lookup.iter().filter_map(RData::ip_addr).collect(),
+            Err(e) if e.is_no_records_found() => vec![],
+            Err(e) => {
+                log::debug!("failed to resolve DDNS host name {host_name}\nError: {e:?}");
+                return None;
+            },
+        };
+
+        Some(DdnsRecord {
+            interface: status.interface.clone(),
+            host_name,
+            ip_address,
+            addresses,
+        })
+    }
+}
+
+impl<R> Runner for DdnsRecordRunner<R>
+where
+    R: Runner<Item = DdnsStatusResult> + Send + Sync,
+{
+    type Item = (DdnsStatusResult, Option<DdnsRecordResult>);
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        let statuses = self.runner.run().await?;
+        let records = match &self.resolver {
+            Some(resolver) => Some(Self::records(resolver, &statuses).await),
+            None => None,
+        };
+
+        Ok((statuses, records))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        future::Future,
+        net::{Ipv4Addr, Ipv6Addr},
+        sync::Arc,
+    };
+
+    use futures::future::ok;
+    use hickory_resolver::proto::{
+        op::{Message, MessageType, ResponseCode},
+        rr::{rdata::{A, AAAA}, Record},
+    };
+    use mockall::mock;
+    use pretty_assertions::assert_eq;
+    use tokio::net::UdpSocket;
+
+    use crate::domain::ddns::DdnsUpdateStatus;
+
+    use super::*;
+
+    mock! {
+        DdnsRunner {}
+
+        impl Runner for DdnsRunner {
+            type Item = DdnsStatusResult;
+
+            fn run(&self) -> impl Future<Output = anyhow::Result<<Self as Runner>::Item>> + Send;
+        }
+    }
+
+    async fn serve(records: HashMap<(&'static str, RecordType), RData>) -> SocketAddr {
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        let records = Arc::new(records);
+
+        tokio::spawn(async move {
+            let mut buf = [0; 512];
+            loop {
+                let (len, peer) = socket.recv_from(&mut buf).await.unwrap();
+                let request = Message::from_vec(&buf[..len]).unwrap();
+
+                let mut response = Message::new();
+                response
+                    .set_id(request.id())
+                    .set_message_type(MessageType::Response)
+                    .set_recursion_desired(request.recursion_desired())
+                    .set_recursion_available(true)
+                    .add_queries(request.queries().to_vec());
+                for query in request.queries() {
+                    let name = query.name().to_string();
+                    match records.get(&(name.trim_end_matches('.'), query.query_type())) {
+                        Some(rdata) => {
+                            response.add_answer(Record::from_rdata(query.name().clone(), 60, rdata.clone()));
+                        },
+                        None => {
+                            response.set_response_code(ResponseCode::NXDomain);
+                        },
+                    }
+                }
+
+                socket.send_to(&response.to_vec().unwrap(), peer).await.unwrap();
+            }
+        });
+
+        addr
+    }
+
+    #[tokio::test]
+    async fn records() {
+        let addr = serve(HashMap::from([
+            (("1.example.com", RecordType::A), RData::A(A(Ipv4Addr::new(192, 0, 2, 1)))),
+            (("2.example.com", RecordType::A), RData::A(A(Ipv4Addr::new(198, 51, 100, 1)))),
+            (("3.example.com", RecordType::AAAA), RData::AAAA(AAAA(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1)))),
+        ])).await;
+
+        let mut mock_runner = MockDdnsRunner::new();
+        mock_runner
+            .expect_run()
+            .times(1)
+            .returning(|| Box::pin(ok(vec![
+                DdnsStatus {
+                    interface: "eth0".to_string(),
+                    ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                    host_name: Some("1.example.com".to_string()),
+                    last_update: None,
+                    update_status: Some(DdnsUpdateStatus::Good),
+                },
+                DdnsStatus {
+                    interface: "eth1".to_string(),
+                    ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2))),
+                    host_name: Some("2.example.com".to_string()),
+                    last_update: None,
+                    update_status: Some(DdnsUpdateStatus::Good),
+                },
+                DdnsStatus {
+                    interface: "eth2".to_string(),
+                    ip_address: Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1))),
+                    host_name: Some("3.example.com".to_string()),
+                    last_update: None,
+                    update_status: Some(DdnsUpdateStatus::NoChange),
+                },
+                DdnsStatus {
+                    interface: "eth3".to_string(),
+                    ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4))),
+                    host_name: Some("4.example.com".to_string()),
+                    last_update: None,
+                    update_status: Some(DdnsUpdateStatus::Good),
+                },
+                DdnsStatus {
+                    interface: "eth4".to_string(),
+                    ip_address: None,
+                    host_name: Some("5.example.com".to_string()),
+                    last_update: None,
+                    update_status: None,
+                },
+            ])));
+
+        let runner = DdnsRecordRunner::new(Some(addr), mock_runner);
+        let (_, records) = runner.run().await.unwrap();
+        assert_eq!(records, Some(vec![
+            DdnsRecord {
+                interface: "eth0".to_string(),
+                host_name: "1.example.com".to_string(),
+                ip_address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+                addresses: vec![IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))],
+            },
+            DdnsRecord {
+                interface: "eth1".to_string(),
+                host_name: "2.example.com".to_string(),
+                ip_address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                addresses: vec![IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1))],
+            },
+            DdnsRecord {
+                interface: "eth2".to_string(),
+                host_name: "3.example.com".to_string(),
+                ip_address: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1)),
+                addresses: vec![IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x1))],
+            },
+            DdnsRecord {
+                interface: "eth3".to_string(),
+                host_name: "4.example.com".to_string(),
+                ip_address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
+                addresses: vec![],
+            },
+        ]));
+    }
+
+    #[tokio::test]
+    async fn disabled() {
+        let mut mock_runner = MockDdnsRunner::new();
+        mock_runner
+            .expect_run()
+            .times(1)
+            .returning(|| Box::pin(ok(vec![
+                DdnsStatus {
+                    interface: "eth0".to_string(),
+                    ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                    host_name: Some("1.example.com".to_string()),
+                    last_update: None,
+                    update_status: Some(DdnsUpdateStatus::Good),
+                },
+            ])));
+
+        let runner = DdnsRecordRunner::new(None, mock_runner);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, (
+            vec![
+                DdnsStatus {
+                    interface: "eth0".to_string(),
+                    ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                    host_name: Some("1.example.com".to_string()),
+                    last_update: None,
+                    update_status: Some(DdnsUpdateStatus::Good),
+                },
+            ],
+            None,
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use futures::TryStreamExt;
+use rtnetlink::{
+    packet_route::{
+        address::{AddressAttribute, AddressMessage},
+        link::{LinkAttribute, LinkMessage},
+    },
+    Handle,
+};
+
+use crate::{
+    domain::interface::{AddrInfo, Interface},
+    service::{interface::InterfaceResult, Runner},
+};
+
+pub struct NetlinkInterfaceRunner<F> {
+    fallback: F,
+}
+
+impl<F> NetlinkInterfaceRunner<F>
+where
+    F: Runner<Item = InterfaceResult> + Send + Sync,
+{
+    pub fn new(fallback: F) -> Self {
+        Self {
+            fallback,
+        }
+    }
+
+    async fn interfaces(&self) -> anyhow::Result<InterfaceResult> {
+        let (connection, handle, _) = rtnetlink::new_connection().context("error opening netlink socket")?;
+        let connection = tokio::spawn(connection);
+
+        let result = Self::query(&handle).await;
+        connection.abort();
+
+        result
+    }
+
+    async fn query(handle: &Handle) -> anyhow::Result<InterfaceResult> {
+        let links: Vec<LinkMessage> = handle
+            .link()
+            .get()
+            .execute()
+            .try_collect()
+            .await
+            .context("error retrieving links")?;
+        let addresses: Vec<AddressMessage> = handle
+            .address()
+            .get()
+            .execute()
+            .try_collect()
+            .await
+            .context("error retrieving addresses")?;
+
+        let mut addr_info = HashMap::<u32, Vec<AddrInfo>>::new();
+        for message in addresses {
+            let mut local = None;
+            let mut peer = None;
+            for attribute in message.attributes {
+                match attribute {
+                    AddressAttribute::Local(ip) => local = Some(ip),
+                    AddressAttribute::Address(ip) => peer = Some(ip),
+                    _ => {},
+                }
+            }
+
+            let (local, address) = match (local, peer) {
+                (Some(local), Some(peer)) if local != peer => (local, Some(peer)),
+                (Some(local), _) | (None, Some(local)) => (local, None),
+                (None, None) => continue,
+            };
+            addr_info
+                .entry(message.header.index)
+                .or_default()
+                .push(AddrInfo {
+                    local,
+                    address,
+                    prefixlen: message.header.prefix_len.into(),
+                });
+        }
+
+        let interfaces = links
+            .into_iter()
+            .filter_map(|link| {
+                let mut ifname = None;
+                let mut operstate = None;
+                for attribute in link.attributes {
+                    match attribute {
+                        LinkAttribute::IfName(name) => ifname = Some(name),
+                        LinkAttribute::OperState(state) => operstate = Some(state.to_string()),
+                        _ => {},
+                    }
+                }
+
+                Some(Interface {
+                    ifname: ifname?,
+                    operstate: operstate.unwrap_or_else(|| "UNKNOWN".to_string()),
+                    addr_info: addr_info.remove(&link.header.index).unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        Ok(interfaces)
+    }
+}
+
+impl<F> Runner for NetlinkInterfaceRunner<F>
+where
+    F: Runner<Item = InterfaceResult> + Send + Sync,
+{
+    type Item = InterfaceResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        match self.interfaces().await {
+            Ok(interfaces) => Ok(interfaces),
+            Err(e) => {
+                log::debug!("failed to retrieve interfaces via netlink, falling back\nError: {e:?}");
+                self.fallback.run().await
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        net::{IpAddr, Ipv4Addr},
+        thread,
+    };
+
+    use mockall::mock;
+    use pretty_assertions::assert_eq;
+    use rtnetlink::{LinkUnspec, LinkVeth};
+
+    use super::*;
+
+    mock! {
+        InterfaceRunner {}
+
+        impl Runner for InterfaceRunner {
+            type Item = InterfaceResult;
+
+            fn run(&self) -> impl Future<Output = anyhow::Result<<Self as Runner>::Item>> + Send;
+        }
+    }
+
+    async fn setup(handle: &Handle) -> anyhow::Result<()> {
+        handle.link().set(LinkUnspec::new_with_index(1).up().build()).execute().await?;
+        handle.link().add(LinkVeth::new("veth0", "veth1").build()).execute().await?;
+
+        let veth0 = handle
+            .link()
+            .get()
+            .match_name("veth0".to_string())
+            .execute()
+            .try_next()
+            .await?
+            .context("veth0 not found")?;
+        handle
+            .address()
+            .add(veth0.header.index, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 24)
+            .execute()
+            .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "requires CAP_SYS_ADMIN for a network namespace"]
+    fn interfaces() {
+        let mut actual = thread::spawn(|| {
+            // Isolate this thread in a fresh network namespace so the test does not touch the host.
+            assert_eq!(unsafe { libc::unshare(libc::CLONE_NEWNET) }, 0, "error creating network namespace");
+
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let (connection, handle, _) = rtnetlink::new_connection().unwrap();
+                tokio::spawn(connection);
+                setup(&handle).await.unwrap();
+
+                let mut mock_fallback = MockInterfaceRunner::new();
+                mock_fallback
+                    .expect_run()
+                    .times(0);
+
+                let runner = NetlinkInterfaceRunner::new(mock_fallback);
+                runner.run().await.unwrap()
+            })
+        }).join().unwrap();
+
+        actual.sort_by(|a, b| a.ifname.cmp(&b.ifname));
+
+        assert_eq!(actual.iter().map(|i| i.ifname.as_str()).collect::<Vec<_>>(), vec!["lo", "veth0", "veth1"]);
+        assert_eq!(actual[0].operstate, "UNKNOWN");
+        assert_eq!(actual[0].addr_info.first(), Some(&AddrInfo {
+            local: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+            address: None,
+            prefixlen: 8,
+        }));
+        assert_eq!(actual[1].operstate, "DOWN");
+        assert_eq!(actual[1].addr_info, vec![
+            AddrInfo {
+                local: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+                address: None,
+                prefixlen: 24,
+            },
+        ]);
+        assert_eq!(actual[2].addr_info, vec![]);
+    }
+}
@@ -1,52 +1,439 @@
-use std::io::ErrorKind;
+use std::{
+    io::ErrorKind,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
 
 use anyhow::{Context, Error};
-use futures::TryStreamExt;
+use futures::{stream::select_all, StreamExt, TryStreamExt};
 use indexmap::IndexMap;
+use rsvici::Client;
+use tokio::time::sleep;
 
 use crate::{
-    domain::ipsec::SA,
+    domain::ipsec::{Backend, Conn, Counters, Event, SA, Stats, TunnelEvents},
     infrastructure::config::env::ViciPath,
-    service::{ipsec::IPsecResult, Runner},
+    service::{
+        ipsec::{IPsecBackendResult, IPsecConnResult, IPsecCounterResult, IPsecEventResult, IPsecResult, IPsecStatsResult},
+        Runner,
+    },
 };
 
+const EVENTS: [&str; 4] = ["ike-updown", "child-updown", "ike-rekey", "child-rekey"];
+
+const EVENT_RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
 type SAs = IndexMap<String, SA>;
 
-pub struct IPsecRunner {
+type Conns = IndexMap<String, Conn>;
+
+type Events = Arc<Mutex<IndexMap<(String, String), TunnelEvents>>>;
+
+pub struct ViciIPsecRunner<F> {
     path: ViciPath,
+    events: Events,
+    fallback: F,
 }
 
-impl IPsecRunner {
-    pub fn new(path: ViciPath) -> Self {
+impl<F> ViciIPsecRunner<F>
+where
+    F: Runner<Item = IPsecResult> + Send + Sync,
+{
+    pub fn new(path: ViciPath, fallback: F) -> Self {
+        let events = Events::default();
+        tokio::spawn(listen(path.clone(), events.clone()));
+
         Self {
             path,
+            events,
+            fallback,
         }
     }
 
-    async fn sas(&self) -> anyhow::Result<IPsecResult> {
+    fn events(&self) -> IPsecEventResult {
+        self.events.lock().unwrap().values().cloned().collect()
+    }
+
+    async fn sas(&self, client: &mut Client) -> anyhow::Result<IPsecResult> {
+        let stream = client.stream_request("list-sas", "list-sa", ());
+        let items: Vec<SAs> = stream.try_collect().await.context("error retrieving IPsec SAs")?;
+        let sas = items.into_iter().flatten().collect();
+
+        Ok(sas)
+    }
+
+    async fn conns(&self, client: &mut Client) -> anyhow::Result<IPsecConnResult> {
+        let stream = client.stream_request("list-conns", "list-conn", ());
+        let items: Vec<Conns> = stream.try_collect().await.context("error retrieving IPsec connections")?;
+        let conns = items.into_iter().flatten().collect();
+
+        Ok(conns)
+    }
+
+    async fn stats(&self, client: &mut Client) -> anyhow::Result<IPsecStatsResult> {
+        let stats: Stats = match client.request("stats", ()).await {
+            Ok(stats) => stats,
+            Err(e) if e.is_unknown_cmd() => {
+                log::debug!("failed to retrieve strongSwan stats (charon may be too old): {e}");
+                return Ok(None);
+            },
+            Err(e) => {
+                return Err(Error::from(e).context("error retrieving strongSwan stats"));
+            },
+        };
+
+        Ok(Some(stats))
+    }
+
+    async fn counters(&self, client: &mut Client) -> anyhow::Result<IPsecCounterResult> {
+        let counters: Counters = match client.request("get-counters", ()).await {
+            Ok(counters) => counters,
+            Err(e) if e.is_unknown_cmd() => {
+                log::debug!("failed to retrieve strongSwan counters (counters plugin may not be loaded): {e}");
+                return Ok(IndexMap::new());
+            },
+            Err(e) => {
+                return Err(Error::from(e).context("error retrieving strongSwan counters"));
+            },
+        };
+
+        // Global counters are returned in the section with an empty name.
+        let counters = counters.counters.into_iter()
+            .find(|(name, _)| name.is_empty())
+            .map(|(_, counters)| counters)
+            .unwrap_or_default();
+
+        Ok(counters)
+    }
+}
+
+impl<F> Runner for ViciIPsecRunner<F>
+where
+    F: Runner<Item = IPsecResult> + Send + Sync,
+{
+    type Item = (IPsecResult, IPsecConnResult, IPsecStatsResult, IPsecCounterResult, IPsecEventResult, IPsecBackendResult);
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
         let mut client = match rsvici::unix::connect(&self.path).await {
             Ok(client) => client,
             Err(e) if e.kind() == ErrorKind::NotFound => {
-                log::debug!("failed to connect to strongSwan at {:?}: {e}", self.path);
-                return Ok(IndexMap::new());
+                log::debug!("failed to connect to strongSwan at {:?}, falling back: {e}", self.path);
+                let sas = self.fallback.run().await.context("error retrieving IPsec SAs via command")?;
+                return Ok((sas, IndexMap::new(), None, IndexMap::new(), self.events(), Backend::Command));
             },
             Err(e) => {
                 return Err(Error::from(e).context("error connecting to strongSwan"));
             },
         };
 
-        let stream = client.stream_request("list-sas", "list-sa", ());
-        let items: Vec<SAs> = stream.try_collect().await.context("error retrieving IPsec SAs")?;
-        let sas = items.into_iter().flatten().collect();
+        let sas = self.sas(&mut client).await?;
+        let conns = self.conns(&mut client).await?;
+        let stats = self.stats(&mut client).await?;
+        let counters = self.counters(&mut client).await?;
 
-        Ok(sas)
+        let events = self.events();
+
+        Ok((sas, conns, stats, counters, events, Backend::Vici))
     }
 }
 
-impl Runner for IPsecRunner {
-    type Item = IPsecResult;
+async fn listen(path: ViciPath, events: Events) {
+    loop {
+        if let Err(e) = subscribe(&path, &events).await {
+            log::debug!("failed to receive IPsec events\nError: {e:?}");
+        }
+        sleep(EVENT_RETRY_INTERVAL).await;
+    }
+}
 
-    async fn run(&self) -> anyhow::Result<Self::Item> {
-        self.sas().await
+async fn subscribe(path: &ViciPath, events: &Events) -> anyhow::Result<()> {
+    let mut client = rsvici::unix::connect(path).await.context("error connecting to strongSwan")?;
+    let streams: Vec<_> = EVENTS
+        .into_iter()
+        .map(|name| client.subscribe::<Event>(name).map_ok(move |event| (name, event)).boxed())
+        .collect();
+
+    let mut stream = select_all(streams);
+    while let Some((name, event)) = stream.try_next().await.context("error receiving IPsec events")? {
+        record(&mut events.lock().unwrap(), name, event, SystemTime::now());
+    }
+
+    Ok(())
+}
+
+fn record(events: &mut IndexMap<(String, String), TunnelEvents>, name: &str, event: Event, now: SystemTime) {
+    for (ike_sa, sa) in event.sas {
+        // IKE SA events are recorded with an empty tunnel name.
+        let tunnels: Vec<_> = match name {
+            "child-updown" | "child-rekey" => sa
+                .child_sas
+                .iter()
+                .map(|(key, child_sa)| child_sa.name().unwrap_or(key).to_string())
+                .collect(),
+            _ => vec![String::new()],
+        };
+
+        for tunnel in tunnels {
+            let entry = events
+                .entry((ike_sa.clone(), tunnel.clone()))
+                .or_insert_with(|| TunnelEvents {
+                    ike_sa: ike_sa.clone(),
+                    tunnel,
+                    ..Default::default()
+                });
+
+            match (name, event.up.as_deref()) {
+                ("ike-rekey" | "child-rekey", _) => entry.rekey += 1,
+                (_, Some("yes")) => entry.up += 1,
+                _ => entry.down += 1,
+            }
+            entry.last_change = Some(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+    use pretty_assertions::assert_eq;
+    use serde::Serialize;
+
+    use crate::domain::ipsec::{StatsIKESAs, StatsPriorities, StatsWorkers};
+
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(untagged)]
+    enum Value {
+        String(&'static str),
+        List(Vec<&'static str>),
+        Section(IndexMap<&'static str, Value>),
+    }
+
+    fn message(sections: IndexMap<&'static str, Value>) -> Vec<u8> {
+        serde_vici::to_vec(&sections).unwrap()
+    }
+
+    #[test]
+    fn stats() {
+        let payload = message(indexmap! {
+            "uptime" => Value::Section(indexmap! {
+                "running" => Value::String("3 days, 01:23:45"),
+                "since" => Value::String("Oct 14 09:12:41 2026"),
+            }),
+            "workers" => Value::Section(indexmap! {
+                "total" => Value::String("16"),
+                "idle" => Value::String("11"),
+                "active" => Value::Section(indexmap! {
+                    "critical" => Value::String("4"),
+                    "high" => Value::String("0"),
+                    "medium" => Value::String("1"),
+                    "low" => Value::String("0"),
+                }),
+            }),
+            "queues" => Value::Section(indexmap! {
+                "critical" => Value::String("0"),
+                "high" => Value::String("0"),
+                "medium" => Value::String("2"),
+                "low" => Value::String("0"),
+            }),
+            "scheduled" => Value::String("6"),
+            "ikesas" => Value::Section(indexmap! {
+                "total" => Value::String("3"),
+                "half-open" => Value::String("1"),
+            }),
+            "plugins" => Value::List(vec!["charon", "aes", "sha1", "vici", "counters"]),
+        });
+
+        let actual: Stats = serde_vici::from_slice(&payload).unwrap();
+        assert_eq!(actual, Stats {
+            workers: StatsWorkers {
+                total: 16,
+                idle: 11,
+                active: StatsPriorities {
+                    critical: 4,
+                    high: 0,
+                    medium: 1,
+                    low: 0,
+                },
+            },
+            queues: StatsPriorities {
+                critical: 0,
+                high: 0,
+                medium: 2,
+                low: 0,
+            },
+            scheduled: 6,
+            ikesas: StatsIKESAs {
+                total: 3,
+                half_open: 1,
+            },
+        });
+    }
+
+    #[test]
+    fn counters() {
+        let payload = message(indexmap! {
+            "counters" => Value::Section(indexmap! {
+                "" => Value::Section(indexmap! {
+                    "ike-rekey-init" => Value::String("2"),
+                    "ike-rekey-resp" => Value::String("1"),
+                    "child-rekey" => Value::String("12"),
+                    "invalid" => Value::String("0"),
+                }),
+                "peer-198.51.100.1" => Value::Section(indexmap! {
+                    "ike-rekey-init" => Value::String("2"),
+                    "ike-rekey-resp" => Value::String("0"),
+                    "child-rekey" => Value::String("8"),
+                    "invalid" => Value::String("0"),
+                }),
+            }),
+            "success" => Value::String("yes"),
+        });
+
+        let actual: Counters = serde_vici::from_slice(&payload).unwrap();
+        assert_eq!(actual, Counters {
+            counters: indexmap! {
+                String::new() => indexmap! {
+                    "ike-rekey-init".to_string() => 2,
+                    "ike-rekey-resp".to_string() => 1,
+                    "child-rekey".to_string() => 12,
+                    "invalid".to_string() => 0,
+                },
+                "peer-198.51.100.1".to_string() => indexmap! {
+                    "ike-rekey-init".to_string() => 2,
+                    "ike-rekey-resp".to_string() => 0,
+                    "child-rekey".to_string() => 8,
+                    "invalid".to_string() => 0,
+                },
+            },
+        });
+    }
+
+    #[test]
+    fn ike_updown() {
+        let now = SystemTime::now();
+        let payload = message(indexmap! {
+            "up" => Value::String("yes"),
+            "peer-198.51.100.1" => Value::Section(indexmap! {
+                "uniqueid" => Value::String("1"),
+                "state" => Value::String("ESTABLISHED"),
+                "child-sas" => Value::Section(indexmap! {
+                    "peer-198.51.100.1-tunnel-1-1" => Value::Section(indexmap! {
+                        "name" => Value::String("peer-198.51.100.1-tunnel-1"),
+                        "state" => Value::String("INSTALLED"),
+                    }),
+                }),
+            }),
+        });
+
+        let event: Event = serde_vici::from_slice(&payload).unwrap();
+        let mut events = IndexMap::new();
+        record(&mut events, "ike-updown", event, now);
+        assert_eq!(events, indexmap! {
+            ("peer-198.51.100.1".to_string(), String::new()) => TunnelEvents {
+                ike_sa: "peer-198.51.100.1".to_string(),
+                tunnel: String::new(),
+                up: 1,
+                down: 0,
+                rekey: 0,
+                last_change: Some(now),
+            },
+        });
+    }
+
+    #[test]
+    fn child_updown() {
+        let now = SystemTime::now();
+        let up = message(indexmap! {
+            "up" => Value::String("yes"),
+            "peer-198.51.100.1" => Value::Section(indexmap! {
+                "uniqueid" => Value::String("1"),
+                "child-sas" => Value::Section(indexmap! {
+                    "peer-198.51.100.1-tunnel-1-1" => Value::Section(indexmap! {
+                        "name" => Value::String("peer-198.51.100.1-tunnel-1"),
+                        "reqid" => Value::String("1"),
+                        "state" => Value::String("INSTALLED"),
+                    }),
+                    "peer-198.51.100.1-tunnel-2-2" => Value::Section(indexmap! {
+                        "name" => Value::String("peer-198.51.100.1-tunnel-2"),
+                        "reqid" => Value::String("2"),
+                        "state" => Value::String("INSTALLED"),
+                    }),
+                }),
+            }),
+        });
+        let down = message(indexmap! {
+            "peer-198.51.100.1" => Value::Section(indexmap! {
+                "uniqueid" => Value::String("1"),
+                "child-sas" => Value::Section(indexmap! {
+                    "peer-198.51.100.1-tunnel-1-1" => Value::Section(indexmap! {
+                        "name" => Value::String("peer-198.51.100.1-tunnel-1"),
+                        "reqid" => Value::String("1"),
+                        "state" => Value::String("DELETING"),
+                    }),
+                }),
+            }),
+        });
+
+        let mut events = IndexMap::new();
+        record(&mut events, "child-updown", serde_vici::from_slice(&up).unwrap(), now);
+        record(&mut events, "child-updown", serde_vici::from_slice(&down).unwrap(), now);
+        assert_eq!(events, indexmap! {
+            ("peer-198.51.100.1".to_string(), "peer-198.51.100.1-tunnel-1".to_string()) => TunnelEvents {
+                ike_sa: "peer-198.51.100.1".to_string(),
+                tunnel: "peer-198.51.100.1-tunnel-1".to_string(),
+                up: 1,
+                down: 1,
+                rekey: 0,
+                last_change: Some(now),
+            },
+            ("peer-198.51.100.1".to_string(), "peer-198.51.100.1-tunnel-2".to_string()) => TunnelEvents {
+                ike_sa: "peer-198.51.100.1".to_string(),
+                tunnel: "peer-198.51.100.1-tunnel-2".to_string(),
+                up: 1,
+                down: 0,
+                rekey: 0,
+                last_change: Some(now),
+            },
+        });
+    }
+
+    #[test]
+    fn child_rekey() {
+        let now = SystemTime::now();
+        let payload = message(indexmap! {
+            "peer-198.51.100.1" => Value::Section(indexmap! {
+                "uniqueid" => Value::String("1"),
+                "child-sas" => Value::Section(indexmap! {
+                    "peer-198.51.100.1-tunnel-1-3" => Value::Section(indexmap! {
+                        "old" => Value::Section(indexmap! {
+                            "name" => Value::String("peer-198.51.100.1-tunnel-1"),
+                            "uniqueid" => Value::String("1"),
+                            "state" => Value::String("REKEYED"),
+                        }),
+                        "new" => Value::Section(indexmap! {
+                            "name" => Value::String("peer-198.51.100.1-tunnel-1"),
+                            "uniqueid" => Value::String("3"),
+                            "state" => Value::String("INSTALLED"),
+                        }),
+                    }),
+                }),
+            }),
+        });
+
+        let event: Event = serde_vici::from_slice(&payload).unwrap();
+        let mut events = IndexMap::new();
+        record(&mut events, "child-rekey", event, now);
+        assert_eq!(events, indexmap! {
+            ("peer-198.51.100.1".to_string(), "peer-198.51.100.1-tunnel-1".to_string()) => TunnelEvents {
+                ike_sa: "peer-198.51.100.1".to_string(),
+                tunnel: "peer-198.51.100.1-tunnel-1".to_string(),
+                up: 0,
+                down: 0,
+                rekey: 1,
+                last_change: Some(now),
+            },
+        });
     }
 }
@@ -12,9 +12,15 @@ use nom::{
 };
 
 pub mod bgp;
+pub mod bgp_json;
 pub mod ddns;
+pub mod dhcp;
+pub mod firewall;
+pub mod hardware;
 pub mod interface;
+pub mod ipsec;
 pub mod load_balance;
+pub mod ospf;
 pub mod pppoe;
 pub mod version;
 
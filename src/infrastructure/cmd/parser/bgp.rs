@@ -1,27 +1,50 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, net::IpAddr, time};
 
 use anyhow::Context;
 use nom::{
     branch::{alt, permutation},
     bytes::complete::{tag, take_till, take_until},
-    character::complete::{multispace0, multispace1, newline, not_line_ending, space1, u32, u64},
-    combinator::{eof, map, map_res, opt},
+    character::complete::{multispace0, multispace1, newline, not_line_ending, space0, space1, u32, u64},
+    combinator::{eof, map, map_parser, map_res, opt},
     error::Error,
     multi::{many0, many1, many_till, separated_list1},
-    sequence::{delimited, terminated},
+    sequence::{delimited, preceded, terminated},
     Finish, IResult, Parser as _,
 };
 
 use crate::{
-    domain::bgp::{BGPNeighbor, BGPStatus},
+    domain::bgp::{BGPMessageCounters, BGPNeighbor, BGPNeighborAddressFamily, BGPNeighborDetail, BGPStatus},
     infrastructure::cmd::parser::{Duration, Parser},
-    service::bgp::BGPStatusResult,
+    service::bgp::{BGPNeighborDetailResult, BGPStatusResult},
 };
 
 const BGP_VERSION: &str = "4";
 
 pub struct BGPParser;
 
+pub struct BGPNeighborDetailParser;
+
+enum BGPMessageType {
+    Open,
+    Update,
+    Notification,
+    Keepalive,
+    RouteRefresh,
+}
+
+enum BGPNeighborDetailLine {
+    Neighbor(IpAddr),
+    Description(String),
+    Timers(u64, u64),
+    Messages(BGPMessageType, Option<u64>, Option<u64>),
+    AddressFamily(String),
+    PrefixesAccepted(u64),
+    PrefixesAdvertised(u64),
+    Connections(u64, u64),
+    LastReset(String),
+    Other,
+}
+
 impl Parser for BGPParser {
     type Context<'a> = ();
     type Item = BGPStatusResult;
@@ -35,6 +58,19 @@ impl Parser for BGPParser {
     }
 }
 
+impl Parser for BGPNeighborDetailParser {
+    type Context<'a> = ();
+    type Item = BGPNeighborDetailResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_bgp_neighbor_details(input)
+            .finish()
+            .map(|(_, details)| details)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse BGP neighbor details")
+    }
+}
+
 fn parse_bgp_neighbor(header: &[&str], line: &[&str]) -> anyhow::Result<BGPNeighbor> {
     let entry: HashMap<_, _> = header.iter().zip(line.iter()).collect();
     if header.len() > entry.len() && entry.get(&"V").is_some_and(|&&v| v != BGP_VERSION) {
@@ -74,6 +110,7 @@ fn parse_bgp_neighbor(header: &[&str], line: &[&str]) -> anyhow::Result<BGPNeigh
             .parse().ok().map(Duration::into),
         state,
         prefixes_received,
+        detail: None,
     })
 }
 
@@ -160,8 +197,8 @@ fn parse_bgp_status(input: &str) -> IResult<&str, BGPStatusResult> {
                     router_id,
                     local_as,
                     table_version,
-                    as_paths,
-                    communities,
+                    as_paths: Some(as_paths),
+                    communities: Some(communities),
                     ebgp_maximum_paths,
                     ibgp_maximum_paths,
                     neighbors,
@@ -172,6 +209,169 @@ fn parse_bgp_status(input: &str) -> IResult<&str, BGPStatusResult> {
     )).parse_complete(input)
 }
 
+fn parse_bgp_neighbor_detail_line(input: &str) -> IResult<&str, BGPNeighborDetailLine> {
+    alt((
+        map(
+            delimited(
+                tag("BGP neighbor is "),
+                map_res(take_till(|c| c == ',' || c == '\n'), str::parse),
+                not_line_ending,
+            ),
+            BGPNeighborDetailLine::Neighbor,
+        ),
+        map(
+            preceded((space0, tag("Description:"), space0), not_line_ending),
+            |description: &str| BGPNeighborDetailLine::Description(description.trim_end().to_string()),
+        ),
+        map(
+            (
+                preceded((take_until("hold time is "), tag("hold time is ")), u64),
+                preceded((take_until("keepalive interval is "), tag("keepalive interval is ")), u64),
+                not_line_ending,
+            ),
+            |(hold_time, keepalive_interval, _)| BGPNeighborDetailLine::Timers(hold_time, keepalive_interval),
+        ),
+        map(
+            (
+                preceded(
+                    space0,
+                    alt((
+                        map(tag("Opens:"), |_| BGPMessageType::Open),
+                        map(tag("Notifications:"), |_| BGPMessageType::Notification),
+                        map(tag("Updates:"), |_| BGPMessageType::Update),
+                        map(tag("Keepalives:"), |_| BGPMessageType::Keepalive),
+                        map(tag("Route Refresh:"), |_| BGPMessageType::RouteRefresh),
+                    )),
+                ),
+                preceded(space1, u64),
+                preceded(space1, u64),
+                space0,
+            ),
+            |(message_type, sent, received, _)| BGPNeighborDetailLine::Messages(message_type, Some(sent), Some(received)),
+        ),
+        map(
+            delimited(
+                (space0, tag("Received"), space1, u64, tag(" messages,"), space1),
+                u64,
+                (tag(" notifications"), not_line_ending),
+            ),
+            |received| BGPNeighborDetailLine::Messages(BGPMessageType::Notification, None, Some(received)),
+        ),
+        map(
+            delimited(
+                (space0, tag("Sent"), space1, u64, tag(" messages,"), space1),
+                u64,
+                (tag(" notifications"), not_line_ending),
+            ),
+            |sent| BGPNeighborDetailLine::Messages(BGPMessageType::Notification, Some(sent), None),
+        ),
+        map(
+            (
+                preceded((space0, tag("Route refresh request: received"), space1), u64),
+                delimited((tag(","), space1, tag("sent"), space1), u64, space0),
+            ),
+            |(received, sent)| BGPNeighborDetailLine::Messages(BGPMessageType::RouteRefresh, Some(sent), Some(received)),
+        ),
+        map(
+            preceded((space0, tag("For address family:"), space0), not_line_ending),
+            |address_family: &str| BGPNeighborDetailLine::AddressFamily(address_family.trim_end().to_string()),
+        ),
+        map(
+            delimited(space0, u64, (tag(" accepted prefixes"), space0)),
+            BGPNeighborDetailLine::PrefixesAccepted,
+        ),
+        map(
+            delimited(space0, u64, (tag(" announced prefixes"), space0)),
+            BGPNeighborDetailLine::PrefixesAdvertised,
+        ),
+        map(
+            (
+                preceded((space0, tag("Connections established"), space1), u64),
+                delimited((tag(";"), space1, tag("dropped"), space1), u64, space0),
+            ),
+            |(established, dropped)| BGPNeighborDetailLine::Connections(established, dropped),
+        ),
+        map(
+            preceded((space0, tag("Last reset"), take_until("due to "), tag("due to ")), not_line_ending),
+            |reason: &str| BGPNeighborDetailLine::LastReset(reason.trim_end().to_string()),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_bgp_neighbor_details(input: &str) -> IResult<&str, BGPNeighborDetailResult> {
+    map(
+        many0(
+            alt((
+                map_parser(
+                    terminated(not_line_ending, newline),
+                    parse_bgp_neighbor_detail_line,
+                ),
+                map(terminated(not_line_ending, newline), |_| BGPNeighborDetailLine::Other),
+            )),
+        ),
+        |lines| {
+            let mut details: Vec<BGPNeighborDetail> = vec![];
+            for line in lines {
+                match (line, details.last_mut()) {
+                    (BGPNeighborDetailLine::Neighbor(neighbor), _) => details.push(BGPNeighborDetail {
+                        neighbor,
+                        description: None,
+                        connections_established: None,
+                        connections_dropped: None,
+                        last_reset_reason: None,
+                        hold_time: None,
+                        keepalive_interval: None,
+                        messages_sent: BGPMessageCounters::default(),
+                        messages_received: BGPMessageCounters::default(),
+                        address_families: vec![],
+                    }),
+                    (BGPNeighborDetailLine::Description(description), Some(detail)) => detail.description = Some(description),
+                    (BGPNeighborDetailLine::Timers(hold_time, keepalive_interval), Some(detail)) => {
+                        detail.hold_time = Some(time::Duration::from_secs(hold_time));
+                        detail.keepalive_interval = Some(time::Duration::from_secs(keepalive_interval));
+                    },
+                    (BGPNeighborDetailLine::Messages(message_type, sent, received), Some(detail)) => {
+                        for (counters, value) in [(&mut detail.messages_sent, sent), (&mut detail.messages_received, received)] {
+                            let counter = match message_type {
+                                BGPMessageType::Open => &mut counters.open,
+                                BGPMessageType::Update => &mut counters.update,
+                                BGPMessageType::Notification => &mut counters.notification,
+                                BGPMessageType::Keepalive => &mut counters.keepalive,
+                                BGPMessageType::RouteRefresh => &mut counters.route_refresh,
+                            };
+                            if value.is_some() {
+                                *counter = value;
+                            }
+                        }
+                    },
+                    (BGPNeighborDetailLine::AddressFamily(address_family), Some(detail)) => detail.address_families.push(BGPNeighborAddressFamily {
+                        address_family,
+                        prefixes_accepted: None,
+                        prefixes_advertised: None,
+                    }),
+                    (BGPNeighborDetailLine::PrefixesAccepted(prefixes), Some(detail)) => {
+                        if let Some(address_family) = detail.address_families.last_mut() {
+                            address_family.prefixes_accepted = Some(prefixes);
+                        }
+                    },
+                    (BGPNeighborDetailLine::PrefixesAdvertised(prefixes), Some(detail)) => {
+                        if let Some(address_family) = detail.address_families.last_mut() {
+                            address_family.prefixes_advertised = Some(prefixes);
+                        }
+                    },
+                    (BGPNeighborDetailLine::Connections(established, dropped), Some(detail)) => {
+                        detail.connections_established = Some(established);
+                        detail.connections_dropped = Some(dropped);
+                    },
+                    (BGPNeighborDetailLine::LastReset(reason), Some(detail)) => detail.last_reset_reason = Some(reason),
+                    _ => {},
+                }
+            }
+            details
+        },
+    ).parse_complete(input)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -229,8 +429,8 @@ mod tests {
             router_id: "192.0.2.1".to_string(),
             local_as: 64496,
             table_version: 128,
-            as_paths: 1,
-            communities: 2,
+            as_paths: Some(1),
+            communities: Some(2),
             ebgp_maximum_paths: None,
             ibgp_maximum_paths: None,
             neighbors: vec![
@@ -246,6 +446,7 @@ mod tests {
                     uptime: Some(Duration::new(4271, 0)),
                     state: None,
                     prefixes_received: Some(9),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
@@ -259,6 +460,7 @@ mod tests {
                     uptime: Some(Duration::new(93780, 0)),
                     state: None,
                     prefixes_received: Some(10),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
@@ -272,6 +474,7 @@ mod tests {
                     uptime: None,
                     state: Some("Connect".to_string()),
                     prefixes_received: None,
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
@@ -285,6 +488,7 @@ mod tests {
                     uptime: Some(Duration::new(12813, 0)),
                     state: None,
                     prefixes_received: Some(0),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
@@ -298,6 +502,7 @@ mod tests {
                     uptime: Some(Duration::new(363960, 0)),
                     state: None,
                     prefixes_received: Some(0),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
@@ -311,6 +516,7 @@ mod tests {
                     uptime: None,
                     state: Some("Connect".to_string()),
                     prefixes_received: None,
+                    detail: None,
                 },
             ],
             sessions: 4,
@@ -346,8 +552,8 @@ mod tests {
             router_id: "192.0.2.1".to_string(),
             local_as: 64496,
             table_version: 128,
-            as_paths: 1,
-            communities: 2,
+            as_paths: Some(1),
+            communities: Some(2),
             ebgp_maximum_paths: Some(8),
             ibgp_maximum_paths: Some(4),
             neighbors: vec![
@@ -363,6 +569,7 @@ mod tests {
                     uptime: Some(Duration::new(4271, 0)),
                     state: None,
                     prefixes_received: Some(9),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
@@ -376,6 +583,7 @@ mod tests {
                     uptime: Some(Duration::new(93780, 0)),
                     state: None,
                     prefixes_received: Some(10),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
@@ -389,6 +597,7 @@ mod tests {
                     uptime: None,
                     state: Some("Connect".to_string()),
                     prefixes_received: None,
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
@@ -402,6 +611,7 @@ mod tests {
                     uptime: Some(Duration::new(12813, 0)),
                     state: None,
                     prefixes_received: Some(0),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
@@ -415,6 +625,7 @@ mod tests {
                     uptime: Some(Duration::new(363960, 0)),
                     state: None,
                     prefixes_received: Some(0),
+                    detail: None,
                 },
                 BGPNeighbor {
                     neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
@@ -428,9 +639,191 @@ mod tests {
                     uptime: None,
                     state: Some("Connect".to_string()),
                     prefixes_received: None,
+                    detail: None,
                 },
             ],
             sessions: 4,
         }));
     }
+
+    #[test]
+    fn neighbor_details_empty() {
+        let parser = BGPNeighborDetailParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn neighbor_details() {
+        let parser = BGPNeighborDetailParser;
+        let input = indoc! {"
+            BGP neighbor is 192.0.2.2, remote AS 64497, local AS 64496, external link
+             Description: upstream-a
+              BGP version 4, local router ID 192.0.2.1, remote router ID 192.0.2.2
+              BGP state = Established, up for 01:11:11
+              Last read 00:00:11, hold time is 90, keepalive interval is 30 seconds
+              Neighbor capabilities:
+                Route refresh: advertised and received (old and new)
+                Address family IPv4 Unicast: advertised and received
+              Received 1000 messages, 0 notifications, 0 in queue
+              Sent 5000 messages, 1 notifications, 0 in queue
+              Route refresh request: received 2, sent 3
+              Minimum time between advertisement runs is 30 seconds
+             For address family: IPv4 Unicast
+              BGP table version 128, neighbor version 128
+              Index 1, Offset 0, Mask 0x2
+              Community attribute sent to this neighbor (both)
+              9 accepted prefixes
+              4 announced prefixes
+
+             Connections established 3; dropped 2
+             Last reset 01:11:12, due to BGP Notification received
+            Local host: 192.0.2.1, Local port: 179
+            Foreign host: 192.0.2.2, Foreign port: 42817
+
+            BGP neighbor is 192.0.2.4, remote AS 64497, local AS 64496, external link
+              BGP version 4, local router ID 192.0.2.1, remote router ID 0.0.0.0
+              BGP state = Connect
+              Last read 00:00:00, hold time is 90, keepalive interval is 30 seconds
+              Received 0 messages, 0 notifications, 0 in queue
+              Sent 0 messages, 0 notifications, 0 in queue
+              Route refresh request: received 0, sent 0
+             For address family: IPv4 Unicast
+              0 accepted prefixes
+
+             Connections established 0; dropped 0
+             Last reset never
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            BGPNeighborDetail {
+                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                description: Some("upstream-a".to_string()),
+                connections_established: Some(3),
+                connections_dropped: Some(2),
+                last_reset_reason: Some("BGP Notification received".to_string()),
+                hold_time: Some(Duration::new(90, 0)),
+                keepalive_interval: Some(Duration::new(30, 0)),
+                messages_sent: BGPMessageCounters {
+                    open: None,
+                    update: None,
+                    notification: Some(1),
+                    keepalive: None,
+                    route_refresh: Some(3),
+                },
+                messages_received: BGPMessageCounters {
+                    open: None,
+                    update: None,
+                    notification: Some(0),
+                    keepalive: None,
+                    route_refresh: Some(2),
+                },
+                address_families: vec![
+                    BGPNeighborAddressFamily {
+                        address_family: "IPv4 Unicast".to_string(),
+                        prefixes_accepted: Some(9),
+                        prefixes_advertised: Some(4),
+                    },
+                ],
+            },
+            BGPNeighborDetail {
+                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
+                description: None,
+                connections_established: Some(0),
+                connections_dropped: Some(0),
+                last_reset_reason: None,
+                hold_time: Some(Duration::new(90, 0)),
+                keepalive_interval: Some(Duration::new(30, 0)),
+                messages_sent: BGPMessageCounters {
+                    open: None,
+                    update: None,
+                    notification: Some(0),
+                    keepalive: None,
+                    route_refresh: Some(0),
+                },
+                messages_received: BGPMessageCounters {
+                    open: None,
+                    update: None,
+                    notification: Some(0),
+                    keepalive: None,
+                    route_refresh: Some(0),
+                },
+                address_families: vec![
+                    BGPNeighborAddressFamily {
+                        address_family: "IPv4 Unicast".to_string(),
+                        prefixes_accepted: Some(0),
+                        prefixes_advertised: None,
+                    },
+                ],
+            },
+        ]);
+    }
+
+    #[test]
+    fn neighbor_details_with_message_statistics() {
+        let parser = BGPNeighborDetailParser;
+        let input = indoc! {"
+            BGP neighbor is 2001:db8::2, remote AS 64497, local AS 64496, external link
+             Description: upstream-b
+              BGP version 4, remote router ID 192.0.2.2
+              BGP state = Established, up for 03:33:33
+              Last read 00:00:05, hold time is 180, keepalive interval is 60 seconds
+              Message statistics:
+                Inq depth is 0
+                Outq depth is 0
+                                     Sent       Rcvd
+                Opens:                  3          3
+                Notifications:          1          0
+                Updates:               20        900
+                Keepalives:          6976       2097
+                Route Refresh:          0          0
+                Capability:             0          0
+                Total:               7000       3000
+              Minimum time between advertisement runs is 30 seconds
+
+             For address family: IPv6 Unicast
+              Community attribute sent to this neighbor(both)
+              11 accepted prefixes
+
+              Connections established 3; dropped 2
+              Last reset 03:33:34, due to Peer closed the session
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            BGPNeighborDetail {
+                neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
+                description: Some("upstream-b".to_string()),
+                connections_established: Some(3),
+                connections_dropped: Some(2),
+                last_reset_reason: Some("Peer closed the session".to_string()),
+                hold_time: Some(Duration::new(180, 0)),
+                keepalive_interval: Some(Duration::new(60, 0)),
+                messages_sent: BGPMessageCounters {
+                    open: Some(3),
+                    update: Some(20),
+                    notification: Some(1),
+                    keepalive: Some(6976),
+                    route_refresh: Some(0),
+                },
+                messages_received: BGPMessageCounters {
+                    open: Some(3),
+                    update: Some(900),
+                    notification: Some(0),
+                    keepalive: Some(2097),
+                    route_refresh: Some(0),
+                },
+                address_families: vec![
+                    BGPNeighborAddressFamily {
+                        address_family: "IPv6 Unicast".to_string(),
+                        prefixes_accepted: Some(11),
+                        prefixes_advertised: None,
+                    },
+                ],
+            },
+        ]);
+    }
 }
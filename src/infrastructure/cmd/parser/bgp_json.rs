@@ -0,0 +1,656 @@
+use std::{collections::BTreeMap, net::IpAddr, time::Duration};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::{
+    domain::bgp::{BGPMessageCounters, BGPNeighbor, BGPNeighborAddressFamily, BGPNeighborDetail, BGPStatus, BGPTable},
+    infrastructure::cmd::parser::Parser,
+    service::bgp::{BGPTableResult, BGPVrfNeighborDetailResult},
+};
+
+const BGP_STATE_ESTABLISHED: &str = "Established";
+
+pub struct BGPJsonParser;
+
+pub struct BGPNeighborDetailJsonParser;
+
+// Address families without any peers are emitted as empty objects.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AddressFamily {
+    Summary(Summary),
+    Empty {},
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Summary {
+    router_id: String,
+    r#as: u32,
+    table_version: u32,
+    #[serde(default)]
+    peers: BTreeMap<String, Peer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Peer {
+    remote_as: u32,
+    version: u32,
+    msg_rcvd: u64,
+    msg_sent: u64,
+    table_version: u32,
+    inq: u64,
+    outq: u64,
+    #[serde(default)]
+    peer_uptime_msec: u64,
+    #[serde(default)]
+    pfx_rcd: Option<u64>,
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NeighborDetail {
+    nbr_desc: Option<String>,
+    connections_established: Option<u64>,
+    connections_dropped: Option<u64>,
+    last_reset_due_to: Option<String>,
+    bgp_timer_hold_time_msecs: Option<u64>,
+    bgp_timer_keep_alive_interval_msecs: Option<u64>,
+    #[serde(default)]
+    message_stats: MessageStats,
+    #[serde(default)]
+    address_family_info: BTreeMap<String, AddressFamilyInfo>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessageStats {
+    opens_sent: Option<u64>,
+    opens_recv: Option<u64>,
+    notifications_sent: Option<u64>,
+    notifications_recv: Option<u64>,
+    updates_sent: Option<u64>,
+    updates_recv: Option<u64>,
+    keepalives_sent: Option<u64>,
+    keepalives_recv: Option<u64>,
+    route_refresh_sent: Option<u64>,
+    route_refresh_recv: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddressFamilyInfo {
+    accepted_prefix_counter: Option<u64>,
+    sent_prefix_counter: Option<u64>,
+}
+
+impl Parser for BGPJsonParser {
+    type Context<'a> = ();
+    type Item = BGPTableResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        if input.trim().is_empty() {
+            return Ok(vec![]);
+        }
+
+        let vrfs: BTreeMap<String, BTreeMap<String, AddressFamily>> = serde_json::from_str(input)
+            .context("failed to parse BGP summary JSON")?;
+
+        let tables = vrfs
+            .into_iter()
+            .flat_map(|(vrf, afs)| {
+                afs.into_iter().filter_map(move |(af, summary)| {
+                    let AddressFamily::Summary(summary) = summary else {
+                        return None;
+                    };
+                    let (afi, safi) = parse_address_family(&af)?;
+                    Some(BGPTable {
+                        vrf: vrf.clone(),
+                        afi,
+                        safi,
+                        status: parse_summary(summary),
+                    })
+                })
+            })
+            .collect();
+        Ok(tables)
+    }
+}
+
+impl Parser for BGPNeighborDetailJsonParser {
+    type Context<'a> = ();
+    type Item = BGPVrfNeighborDetailResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        if input.trim().is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Neighbors are keyed by address next to attributes of the VRF such as vrfId and vrfName.
+        let vrfs: BTreeMap<String, BTreeMap<String, serde_json::Value>> = serde_json::from_str(input)
+            .context("failed to parse BGP neighbors JSON")?;
+
+        let mut details = vec![];
+        for (vrf, entries) in vrfs {
+            for (key, value) in entries {
+                let Ok(neighbor) = key.parse() else {
+                    continue;
+                };
+                let detail = serde_json::from_value(value)
+                    .with_context(|| format!("failed to parse BGP neighbor {key} in VRF {vrf}"))?;
+                details.push((vrf.clone(), parse_neighbor_detail(neighbor, detail)));
+            }
+        }
+        Ok(details)
+    }
+}
+
+fn parse_address_family(af: &str) -> Option<(String, String)> {
+    let (afi, safi) = ["ipv4", "ipv6", "l2Vpn"]
+        .into_iter()
+        .find_map(|afi| af.strip_prefix(afi).map(|safi| (afi.to_lowercase(), safi)))?;
+
+    let mut kebab = String::new();
+    for (i, c) in safi.char_indices() {
+        if c.is_ascii_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+        kebab.push(c.to_ascii_lowercase());
+    }
+    (!kebab.is_empty()).then_some((afi, kebab))
+}
+
+fn parse_address_family_name(af: &str) -> String {
+    // Keep the names shown by the text output (e.g. "IPv4 Unicast").
+    let Some((afi, safi)) = [("ipv4", "IPv4"), ("ipv6", "IPv6"), ("l2Vpn", "L2VPN")]
+        .into_iter()
+        .find_map(|(prefix, afi)| af.strip_prefix(prefix).map(|safi| (afi, safi)))
+    else {
+        return af.to_string();
+    };
+    let safi = match safi {
+        "Unicast" => "Unicast",
+        "Multicast" => "Multicast",
+        "LabeledUnicast" => "Labeled Unicast",
+        "Vpn" => "VPN",
+        "Flowspec" => "Flowspec",
+        "Evpn" => "EVPN",
+        _ => return af.to_string(),
+    };
+    format!("{afi} {safi}")
+}
+
+fn parse_summary(summary: Summary) -> BGPStatus {
+    let neighbors: Vec<_> = summary
+        .peers
+        .into_iter()
+        // Unnumbered peers are keyed by interface name and cannot be represented by address.
+        .filter_map(|(neighbor, peer)| Some(parse_peer(neighbor.parse().ok()?, peer)))
+        .collect();
+    let sessions = neighbors.iter().filter(|n| n.state.is_none()).count() as u64;
+
+    BGPStatus {
+        router_id: summary.router_id,
+        local_as: summary.r#as,
+        table_version: summary.table_version,
+        as_paths: None,
+        communities: None,
+        ebgp_maximum_paths: None,
+        ibgp_maximum_paths: None,
+        neighbors,
+        sessions,
+    }
+}
+
+fn parse_peer(neighbor: IpAddr, peer: Peer) -> BGPNeighbor {
+    // Keep the semantics of the text summary, which shows received prefixes in place of the state once established.
+    let (state, prefixes_received) = match peer.state.as_str() {
+        BGP_STATE_ESTABLISHED => (None, Some(peer.pfx_rcd.unwrap_or_default())),
+        _ => (Some(peer.state), None),
+    };
+
+    BGPNeighbor {
+        neighbor,
+        version: peer.version,
+        remote_as: peer.remote_as,
+        messages_received: peer.msg_rcvd,
+        messages_sent: peer.msg_sent,
+        table_version: peer.table_version,
+        in_queue: peer.inq,
+        out_queue: peer.outq,
+        uptime: (peer.peer_uptime_msec > 0).then(|| Duration::from_millis(peer.peer_uptime_msec)),
+        state,
+        prefixes_received,
+        detail: None,
+    }
+}
+
+fn parse_neighbor_detail(neighbor: IpAddr, detail: NeighborDetail) -> BGPNeighborDetail {
+    let stats = detail.message_stats;
+    let address_families = detail
+        .address_family_info
+        .into_iter()
+        .map(|(af, info)| BGPNeighborAddressFamily {
+            address_family: parse_address_family_name(&af),
+            prefixes_accepted: info.accepted_prefix_counter,
+            prefixes_advertised: info.sent_prefix_counter,
+        })
+        .collect();
+
+    BGPNeighborDetail {
+        neighbor,
+        description: detail.nbr_desc,
+        connections_established: detail.connections_established,
+        connections_dropped: detail.connections_dropped,
+        last_reset_reason: detail.last_reset_due_to,
+        hold_time: detail.bgp_timer_hold_time_msecs.map(Duration::from_millis),
+        keepalive_interval: detail.bgp_timer_keep_alive_interval_msecs.map(Duration::from_millis),
+        messages_sent: BGPMessageCounters {
+            open: stats.opens_sent,
+            update: stats.updates_sent,
+            notification: stats.notifications_sent,
+            keepalive: stats.keepalives_sent,
+            route_refresh: stats.route_refresh_sent,
+        },
+        messages_received: BGPMessageCounters {
+            open: stats.opens_recv,
+            update: stats.updates_recv,
+            notification: stats.notifications_recv,
+            keepalive: stats.keepalives_recv,
+            route_refresh: stats.route_refresh_recv,
+        },
+        address_families,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let parser = BGPJsonParser;
+        let input = "% Unknown command";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn empty() {
+        let parser = BGPJsonParser;
+
+        assert_eq!(parser.parse("", ()).unwrap(), vec![]);
+        assert_eq!(parser.parse("{}\n", ()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn vrfs() {
+        let parser = BGPJsonParser;
+        let input = indoc! {r#"
+            {
+            "default":{
+              "ipv4Unicast":{
+                "routerId":"192.0.2.1",
+                "as":64496,
+                "vrfId":0,
+                "vrfName":"default",
+                "tableVersion":128,
+                "ribCount":19,
+                "peerCount":3,
+                "peers":{
+                  "192.0.2.2":{
+                    "hostname":"r2",
+                    "remoteAs":64497,
+                    "localAs":64496,
+                    "version":4,
+                    "msgRcvd":1000,
+                    "msgSent":5000,
+                    "tableVersion":0,
+                    "outq":5,
+                    "inq":1,
+                    "peerUptime":"01:11:11",
+                    "peerUptimeMsec":4271000,
+                    "pfxRcd":9,
+                    "pfxSnt":4,
+                    "state":"Established",
+                    "peerState":"OK",
+                    "connectionsEstablished":3,
+                    "connectionsDropped":2,
+                    "idType":"ipv4"
+                  },
+                  "192.0.2.4":{
+                    "remoteAs":64497,
+                    "localAs":64496,
+                    "version":4,
+                    "msgRcvd":0,
+                    "msgSent":0,
+                    "tableVersion":0,
+                    "outq":0,
+                    "inq":0,
+                    "peerUptime":"never",
+                    "peerUptimeMsec":0,
+                    "state":"Active",
+                    "peerState":"OK",
+                    "connectionsEstablished":0,
+                    "connectionsDropped":0,
+                    "idType":"ipv4"
+                  },
+                  "eth0":{
+                    "remoteAs":64499,
+                    "version":4,
+                    "msgRcvd":10,
+                    "msgSent":10,
+                    "tableVersion":0,
+                    "outq":0,
+                    "inq":0,
+                    "peerUptimeMsec":1000,
+                    "pfxRcd":1,
+                    "state":"Established",
+                    "idType":"interface"
+                  }
+                },
+                "failedPeers":1,
+                "totalPeers":3,
+                "dynamicPeers":0,
+                "bestPath":{
+                  "multiPathRelax":"false"
+                }
+              },
+              "ipv6Unicast":{
+              },
+              "l2VpnEvpn":{
+                "routerId":"192.0.2.1",
+                "as":64496,
+                "vrfId":0,
+                "vrfName":"default",
+                "tableVersion":12,
+                "peers":{
+                  "2001:db8::2":{
+                    "remoteAs":64497,
+                    "version":4,
+                    "msgRcvd":3000,
+                    "msgSent":7000,
+                    "tableVersion":0,
+                    "outq":7,
+                    "inq":3,
+                    "peerUptimeMsec":12813000,
+                    "pfxRcd":11,
+                    "state":"Established"
+                  }
+                }
+              }
+            }
+            ,
+            "RED":{
+              "ipv4Unicast":{
+                "routerId":"198.51.100.1",
+                "as":64496,
+                "vrfId":5,
+                "vrfName":"RED",
+                "tableVersion":3,
+                "peers":{
+                }
+              }
+            }
+            }
+        "#};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            BGPTable {
+                vrf: "RED".to_string(),
+                afi: "ipv4".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "198.51.100.1".to_string(),
+                    local_as: 64496,
+                    table_version: 3,
+                    as_paths: None,
+                    communities: None,
+                    ebgp_maximum_paths: None,
+                    ibgp_maximum_paths: None,
+                    neighbors: vec![],
+                    sessions: 0,
+                },
+            },
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "ipv4".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 128,
+                    as_paths: None,
+                    communities: None,
+                    ebgp_maximum_paths: None,
+                    ibgp_maximum_paths: None,
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 1000,
+                            messages_sent: 5000,
+                            table_version: 0,
+                            in_queue: 1,
+                            out_queue: 5,
+                            uptime: Some(Duration::new(4271, 0)),
+                            state: None,
+                            prefixes_received: Some(9),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 0,
+                            messages_sent: 0,
+                            table_version: 0,
+                            in_queue: 0,
+                            out_queue: 0,
+                            uptime: None,
+                            state: Some("Active".to_string()),
+                            prefixes_received: None,
+                            detail: None,
+                        },
+                    ],
+                    sessions: 1,
+                },
+            },
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "l2vpn".to_string(),
+                safi: "evpn".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 12,
+                    as_paths: None,
+                    communities: None,
+                    ebgp_maximum_paths: None,
+                    ibgp_maximum_paths: None,
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 3000,
+                            messages_sent: 7000,
+                            table_version: 0,
+                            in_queue: 3,
+                            out_queue: 7,
+                            uptime: Some(Duration::new(12813, 0)),
+                            state: None,
+                            prefixes_received: Some(11),
+                            detail: None,
+                        },
+                    ],
+                    sessions: 1,
+                },
+            },
+        ]);
+    }
+
+    #[test]
+    fn neighbor_details_invalid() {
+        let parser = BGPNeighborDetailJsonParser;
+        let input = "% Unknown command";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn neighbor_details_empty() {
+        let parser = BGPNeighborDetailJsonParser;
+
+        assert_eq!(parser.parse("", ()).unwrap(), vec![]);
+        assert_eq!(parser.parse("{}\n", ()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn neighbor_details() {
+        let parser = BGPNeighborDetailJsonParser;
+        let input = indoc! {r#"
+            {
+            "default":{
+              "vrfId":0,
+              "vrfName":"default",
+              "192.0.2.2":{
+                "remoteAs":64497,
+                "localAs":64496,
+                "nbrExternalLink":true,
+                "nbrDesc":"upstream-a",
+                "hostname":"r2",
+                "bgpVersion":4,
+                "remoteRouterId":"192.0.2.2",
+                "bgpState":"Established",
+                "bgpTimerUpMsec":4271000,
+                "bgpTimerHoldTimeMsecs":90000,
+                "bgpTimerKeepAliveIntervalMsecs":30000,
+                "messageStats":{
+                  "depthInq":0,
+                  "depthOutq":0,
+                  "opensSent":3,
+                  "opensRecv":3,
+                  "notificationsSent":1,
+                  "notificationsRecv":0,
+                  "updatesSent":20,
+                  "updatesRecv":900,
+                  "keepalivesSent":6976,
+                  "keepalivesRecv":2097,
+                  "routeRefreshSent":0,
+                  "routeRefreshRecv":0,
+                  "capabilitySent":0,
+                  "capabilityRecv":0,
+                  "totalSent":7000,
+                  "totalRecv":3000
+                },
+                "addressFamilyInfo":{
+                  "ipv4Unicast":{
+                    "commAttriSentToNbr":"extendedAndStandard",
+                    "acceptedPrefixCounter":9,
+                    "sentPrefixCounter":4
+                  }
+                },
+                "connectionsEstablished":3,
+                "connectionsDropped":2,
+                "lastResetTimerMsecs":4272000,
+                "lastResetDueTo":"BGP Notification received"
+              }
+            }
+            ,
+            "RED":{
+              "vrfId":7,
+              "vrfName":"RED",
+              "198.51.100.2":{
+                "remoteAs":64498,
+                "localAs":64496,
+                "bgpVersion":4,
+                "bgpState":"Active",
+                "bgpTimerHoldTimeMsecs":180000,
+                "bgpTimerKeepAliveIntervalMsecs":60000,
+                "addressFamilyInfo":{
+                  "ipv6Unicast":{
+                    "acceptedPrefixCounter":0
+                  },
+                  "l2VpnEvpn":{
+                    "acceptedPrefixCounter":0
+                  }
+                },
+                "connectionsEstablished":0,
+                "connectionsDropped":0,
+                "lastReset":"never"
+              }
+            }
+            }
+        "#};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            ("RED".to_string(), BGPNeighborDetail {
+                neighbor: IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2)),
+                description: None,
+                connections_established: Some(0),
+                connections_dropped: Some(0),
+                last_reset_reason: None,
+                hold_time: Some(Duration::new(180, 0)),
+                keepalive_interval: Some(Duration::new(60, 0)),
+                messages_sent: BGPMessageCounters::default(),
+                messages_received: BGPMessageCounters::default(),
+                address_families: vec![
+                    BGPNeighborAddressFamily {
+                        address_family: "IPv6 Unicast".to_string(),
+                        prefixes_accepted: Some(0),
+                        prefixes_advertised: None,
+                    },
+                    BGPNeighborAddressFamily {
+                        address_family: "L2VPN EVPN".to_string(),
+                        prefixes_accepted: Some(0),
+                        prefixes_advertised: None,
+                    },
+                ],
+            }),
+            ("default".to_string(), BGPNeighborDetail {
+                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                description: Some("upstream-a".to_string()),
+                connections_established: Some(3),
+                connections_dropped: Some(2),
+                last_reset_reason: Some("BGP Notification received".to_string()),
+                hold_time: Some(Duration::new(90, 0)),
+                keepalive_interval: Some(Duration::new(30, 0)),
+                messages_sent: BGPMessageCounters {
+                    open: Some(3),
+                    update: Some(20),
+                    notification: Some(1),
+                    keepalive: Some(6976),
+                    route_refresh: Some(0),
+                },
+                messages_received: BGPMessageCounters {
+                    open: Some(3),
+                    update: Some(900),
+                    notification: Some(0),
+                    keepalive: Some(2097),
+                    route_refresh: Some(0),
+                },
+                address_families: vec![
+                    BGPNeighborAddressFamily {
+                        address_family: "IPv4 Unicast".to_string(),
+                        prefixes_accepted: Some(9),
+                        prefixes_advertised: Some(4),
+                    },
+                ],
+            }),
+        ]);
+    }
+}
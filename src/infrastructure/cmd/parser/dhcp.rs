@@ -0,0 +1,259 @@
+use anyhow::Context;
+use chrono::NaiveDateTime;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{multispace0, newline, not_line_ending, space0, space1, u64},
+    combinator::{eof, map, map_res, opt, recognize},
+    error::Error,
+    multi::many0,
+    sequence::{preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::dhcp::{DhcpLease, DhcpPool},
+    infrastructure::cmd::parser::Parser,
+    service::dhcp::{DhcpLeaseResult, DhcpStatisticsResult},
+};
+
+pub struct DhcpStatisticsParser;
+
+pub struct DhcpLeaseParser;
+
+impl Parser for DhcpStatisticsParser {
+    type Context<'a> = ();
+    type Item = DhcpStatisticsResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_dhcp_statistics(input)
+            .finish()
+            .map(|(_, pools)| pools)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse DHCP statistics")
+    }
+}
+
+impl Parser for DhcpLeaseParser {
+    type Context<'a> = ();
+    type Item = DhcpLeaseResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_dhcp_leases(input)
+            .finish()
+            .map(|(_, leases)| leases)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse DHCP leases")
+    }
+}
+
+fn parse_word(input: &str) -> IResult<&str, &str> {
+    take_till1(|c: char| c.is_ascii_whitespace()).parse_complete(input)
+}
+
+fn parse_table_header<'a>(header: &'static str) -> impl nom::Parser<&'a str, Output = (), Error = Error<&'a str>> {
+    map(
+        (
+            many0(terminated(space0, newline)),
+            tag(header),
+            not_line_ending,
+            newline,
+            tag("----"),
+            not_line_ending,
+            newline,
+        ),
+        |_| (),
+    )
+}
+
+fn parse_dhcp_statistics(input: &str) -> IResult<&str, DhcpStatisticsResult> {
+    alt((
+        preceded(
+            parse_table_header("pool"),
+            many0(
+                map(
+                    terminated(
+                        (
+                            terminated(map(parse_word, &str::to_string), space1),
+                            terminated(u64, space1),
+                            terminated(u64, space1),
+                            u64,
+                        ),
+                        (space0, newline),
+                    ),
+                    |(pool, pool_size, leased, available)| {
+                        DhcpPool {
+                            pool,
+                            pool_size,
+                            leased,
+                            available,
+                        }
+                    },
+                ),
+            ),
+        ),
+        map(
+            (multispace0, eof),
+            |_| vec![],
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_dhcp_leases(input: &str) -> IResult<&str, DhcpLeaseResult> {
+    alt((
+        preceded(
+            parse_table_header("IP address"),
+            many0(
+                map(
+                    (
+                        terminated(map_res(parse_word, str::parse), space1),
+                        terminated(map(parse_word, &str::to_string), space1),
+                        terminated(
+                            map(
+                                recognize((parse_word, opt((space1, parse_time)))),
+                                |s: &str| NaiveDateTime::parse_from_str(s, "%Y/%m/%d %H:%M:%S").ok(),
+                            ),
+                            space1,
+                        ),
+                        map(parse_word, &str::to_string),
+                        terminated(
+                            map(opt(preceded(space1, not_line_ending)), |s: Option<&str>| {
+                                s.map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+                            }),
+                            newline,
+                        ),
+                    ),
+                    |(ip_address, mac_address, expiration, pool, client_name)| {
+                        DhcpLease {
+                            ip_address,
+                            mac_address,
+                            expiration,
+                            pool,
+                            client_name,
+                        }
+                    },
+                ),
+            ),
+        ),
+        map(
+            (multispace0, eof),
+            |_| vec![],
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_time(input: &str) -> IResult<&str, &str> {
+    recognize((u64, tag(":"), u64, tag(":"), u64)).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use chrono::NaiveDate;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn statistics_invalid() {
+        let parser = DhcpStatisticsParser;
+        let input = "Invalid command: show dhcp [statistics]\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn statistics_empty() {
+        let parser = DhcpStatisticsParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn statistics() {
+        let parser = DhcpStatisticsParser;
+        let input = indoc! {"
+            pool                                    pool size   # leased    # avail
+            ----                                    ---------   --------    -------
+            LAN                                     201         12          189
+            GUEST                                   101         0           101
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            DhcpPool {
+                pool: "LAN".to_string(),
+                pool_size: 201,
+                leased: 12,
+                available: 189,
+            },
+            DhcpPool {
+                pool: "GUEST".to_string(),
+                pool_size: 101,
+                leased: 0,
+                available: 101,
+            },
+        ]);
+    }
+
+    #[test]
+    fn leases_invalid() {
+        let parser = DhcpLeaseParser;
+        let input = "Invalid command: show dhcp [leases]\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn leases_empty() {
+        let parser = DhcpLeaseParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn leases() {
+        let parser = DhcpLeaseParser;
+        let input = indoc! {"
+
+            IP address      Hardware Address   Lease expiration     Pool       Client Name
+            ----------      ----------------   ----------------     ----       -----------
+            192.168.1.10    00:11:22:33:44:55  2006/01/02 15:04:05  LAN        laptop
+            192.168.1.11    66:77:88:99:aa:bb  2006/01/03 03:00:00  LAN
+            192.168.2.10    cc:dd:ee:ff:00:11  never                GUEST      printer
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            DhcpLease {
+                ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+                mac_address: "00:11:22:33:44:55".to_string(),
+                expiration: NaiveDate::from_ymd_opt(2006, 1, 2).and_then(|d| d.and_hms_opt(15, 4, 5)),
+                pool: "LAN".to_string(),
+                client_name: Some("laptop".to_string()),
+            },
+            DhcpLease {
+                ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 11)),
+                mac_address: "66:77:88:99:aa:bb".to_string(),
+                expiration: NaiveDate::from_ymd_opt(2006, 1, 3).and_then(|d| d.and_hms_opt(3, 0, 0)),
+                pool: "LAN".to_string(),
+                client_name: None,
+            },
+            DhcpLease {
+                ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 2, 10)),
+                mac_address: "cc:dd:ee:ff:00:11".to_string(),
+                expiration: None,
+                pool: "GUEST".to_string(),
+                client_name: Some("printer".to_string()),
+            },
+        ]);
+    }
+}
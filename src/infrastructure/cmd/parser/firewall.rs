@@ -0,0 +1,400 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till, take_till1, take_while},
+    character::complete::{multispace0, newline, not_line_ending, space0, space1, u32},
+    combinator::{eof, map, map_res, opt, peek, verify},
+    error::Error,
+    multi::{many0, many_till},
+    sequence::{delimited, preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::{
+        firewall::{FirewallRule, FirewallRuleset, NatRule},
+        pppoe::{ByteSize, PacketSize},
+    },
+    infrastructure::cmd::parser::Parser,
+    service::firewall::{FirewallResult, NatResult},
+};
+
+pub struct FirewallParser;
+
+pub struct NatParser;
+
+impl Parser for FirewallParser {
+    type Context<'a> = ();
+    type Item = FirewallResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_firewall_rulesets(input)
+            .finish()
+            .map(|(_, rulesets)| rulesets)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse firewall statistics")
+    }
+}
+
+impl Parser for NatParser {
+    type Context<'a> = ();
+    type Item = NatResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_nat_rules(input)
+            .finish()
+            .map(|(_, rules)| rules)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse NAT statistics")
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, &str> {
+    terminated(not_line_ending, newline).parse_complete(input)
+}
+
+fn parse_word(input: &str) -> IResult<&str, &str> {
+    take_till1(|c: char| c.is_ascii_whitespace()).parse_complete(input)
+}
+
+fn parse_description(input: &str) -> IResult<&str, Option<String>> {
+    map(
+        terminated(opt(preceded(space1, not_line_ending)), newline),
+        |description| description.map(str::trim).filter(|d| !d.is_empty()).map(str::to_string),
+    ).parse_complete(input)
+}
+
+fn parse_table_header(input: &str) -> IResult<&str, ()> {
+    map(
+        (
+            many_till(
+                verify(parse_line, |line: &str| !line.starts_with("---")),
+                peek((tag("rule"), space1)),
+            ),
+            parse_line,
+            preceded(tag("----"), parse_line),
+        ),
+        |_| (),
+    ).parse_complete(input)
+}
+
+fn parse_firewall_banner(input: &str) -> IResult<&str, ()> {
+    map(
+        terminated(
+            alt((
+                map((alt((tag("IPv4"), tag("IPv6"))), tag(" Firewall Rulesets Statistics:")), |_| ()),
+                map((tag("---"), take_while(|c| c == '-')), |_| ()),
+                map(space0, |_| ()),
+            )),
+            (space0, newline),
+        ),
+        |_| (),
+    ).parse_complete(input)
+}
+
+fn parse_firewall_rulesets(input: &str) -> IResult<&str, FirewallResult> {
+    alt((
+        map(
+            (multispace0, eof),
+            |_| vec![],
+        ),
+        map(
+            terminated(
+                many0(
+                    alt((
+                        map(parse_firewall_ruleset, Some),
+                        map(parse_firewall_banner, |_| None),
+                    )),
+                ),
+                eof,
+            ),
+            |rulesets| rulesets.into_iter().flatten().collect(),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_firewall_ruleset(input: &str) -> IResult<&str, FirewallRuleset> {
+    map(
+        (
+            terminated(
+                map(alt((tag("IPv4"), tag("IPv6"))), str::to_lowercase),
+                (space1, tag("Firewall"), space1),
+            ),
+            terminated(
+                delimited(tag("\""), map(take_till(|c| c == '"'), &str::to_string), tag("\":")),
+                (space0, newline),
+            ),
+            preceded(
+                parse_table_header,
+                many0(
+                    map(
+                        (
+                            terminated(u32, space1),
+                            terminated(map_res(parse_word, PacketSize::from_str), space1),
+                            terminated(map_res(parse_word, ByteSize::from_str), space1),
+                            map(parse_word, &str::to_string),
+                            parse_description,
+                        ),
+                        |(rule, packets, bytes, action, description)| {
+                            FirewallRule {
+                                rule,
+                                packets,
+                                bytes,
+                                action,
+                                description,
+                            }
+                        },
+                    ),
+                ),
+            ),
+        ),
+        |(ip_version, name, rules)| {
+            FirewallRuleset {
+                ip_version,
+                name,
+                rules,
+            }
+        },
+    ).parse_complete(input)
+}
+
+fn parse_nat_rules(input: &str) -> IResult<&str, NatResult> {
+    alt((
+        preceded(
+            parse_table_header,
+            many0(
+                map(
+                    (
+                        terminated(u32, space1),
+                        terminated(map_res(parse_word, PacketSize::from_str), space1),
+                        terminated(map_res(parse_word, ByteSize::from_str), space1),
+                        terminated(map(parse_word, &str::to_string), space1),
+                        terminated(map(parse_word, parse_interface), space1),
+                        map(parse_word, parse_interface),
+                        parse_description,
+                    ),
+                    |(rule, packets, bytes, nat_type, inbound_interface, outbound_interface, description)| {
+                        NatRule {
+                            rule,
+                            packets,
+                            bytes,
+                            nat_type,
+                            inbound_interface,
+                            outbound_interface,
+                            description,
+                        }
+                    },
+                ),
+            ),
+        ),
+        map(
+            (multispace0, eof),
+            |_| vec![],
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_interface(interface: &str) -> Option<String> {
+    Some(interface).filter(|i| *i != "-").map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use number_prefix::{NumberPrefix, Prefix};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn firewall_invalid() {
+        let parser = FirewallParser;
+        let input = "Invalid command: show firewall [statistics]\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn firewall_empty() {
+        let parser = FirewallParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn firewall() {
+        let parser = FirewallParser;
+        let input = indoc! {r#"
+            IPv4 Firewall Rulesets Statistics:
+            --------------------------------------------------------------------------------
+            IPv4 Firewall "WAN_IN":
+
+             Active on (eth0,IN)
+
+            rule  packets   bytes     action  description
+            ----  -------   -----     ------  -----------
+            10    85.4K     117.6M    ACCEPT  Allow established/related
+            20    1         40        DROP    Drop invalid state
+            10000 26        1.6K      DROP    DEFAULT ACTION
+
+            --------------------------------------------------------------------------------
+            IPv4 Firewall "WAN_LOCAL":
+
+             Active on (eth0,LOCAL)
+
+            rule  packets   bytes     action  description
+            ----  -------   -----     ------  -----------
+            10    0         0         ACCEPT
+            10000 3         180       DROP    DEFAULT ACTION
+
+            --------------------------------------------------------------------------------
+            IPv6 Firewall "WANv6_IN":
+
+             Active on (eth0,IN)
+
+            rule  packets   bytes     action  description
+            ----  -------   -----     ------  -----------
+            10    12        1.2K      ACCEPT  Allow established/related
+            10000 0         0         DROP    DEFAULT ACTION
+
+        "#};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            FirewallRuleset {
+                ip_version: "ipv4".to_string(),
+                name: "WAN_IN".to_string(),
+                rules: vec![
+                    FirewallRule {
+                        rule: 10,
+                        packets: NumberPrefix::Prefixed(Prefix::Kilo, 85.4).into(),
+                        bytes: NumberPrefix::Prefixed(Prefix::Mega, 117.6).into(),
+                        action: "ACCEPT".to_string(),
+                        description: Some("Allow established/related".to_string()),
+                    },
+                    FirewallRule {
+                        rule: 20,
+                        packets: NumberPrefix::Standalone(1.0).into(),
+                        bytes: NumberPrefix::Standalone(40.0).into(),
+                        action: "DROP".to_string(),
+                        description: Some("Drop invalid state".to_string()),
+                    },
+                    FirewallRule {
+                        rule: 10000,
+                        packets: NumberPrefix::Standalone(26.0).into(),
+                        bytes: NumberPrefix::Prefixed(Prefix::Kilo, 1.6).into(),
+                        action: "DROP".to_string(),
+                        description: Some("DEFAULT ACTION".to_string()),
+                    },
+                ],
+            },
+            FirewallRuleset {
+                ip_version: "ipv4".to_string(),
+                name: "WAN_LOCAL".to_string(),
+                rules: vec![
+                    FirewallRule {
+                        rule: 10,
+                        packets: NumberPrefix::Standalone(0.0).into(),
+                        bytes: NumberPrefix::Standalone(0.0).into(),
+                        action: "ACCEPT".to_string(),
+                        description: None,
+                    },
+                    FirewallRule {
+                        rule: 10000,
+                        packets: NumberPrefix::Standalone(3.0).into(),
+                        bytes: NumberPrefix::Standalone(180.0).into(),
+                        action: "DROP".to_string(),
+                        description: Some("DEFAULT ACTION".to_string()),
+                    },
+                ],
+            },
+            FirewallRuleset {
+                ip_version: "ipv6".to_string(),
+                name: "WANv6_IN".to_string(),
+                rules: vec![
+                    FirewallRule {
+                        rule: 10,
+                        packets: NumberPrefix::Standalone(12.0).into(),
+                        bytes: NumberPrefix::Prefixed(Prefix::Kilo, 1.2).into(),
+                        action: "ACCEPT".to_string(),
+                        description: Some("Allow established/related".to_string()),
+                    },
+                    FirewallRule {
+                        rule: 10000,
+                        packets: NumberPrefix::Standalone(0.0).into(),
+                        bytes: NumberPrefix::Standalone(0.0).into(),
+                        action: "DROP".to_string(),
+                        description: Some("DEFAULT ACTION".to_string()),
+                    },
+                ],
+            },
+        ]);
+    }
+
+    #[test]
+    fn nat_invalid() {
+        let parser = NatParser;
+        let input = "Invalid command: show nat [statistics]\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn nat_empty() {
+        let parser = NatParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn nat() {
+        let parser = NatParser;
+        let input = indoc! {"
+
+            rule   pkts        bytes   type  IN    OUT   description
+            ----   ----        -----   ----  --    ---   -----------
+            1      12          720     DST   eth0  -     port forward web
+            5000   2.3K        191K    MASQ  -     eth0  masquerade for WAN
+            5010   0           0       SRC   -     eth1
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            NatRule {
+                rule: 1,
+                packets: NumberPrefix::Standalone(12.0).into(),
+                bytes: NumberPrefix::Standalone(720.0).into(),
+                nat_type: "DST".to_string(),
+                inbound_interface: Some("eth0".to_string()),
+                outbound_interface: None,
+                description: Some("port forward web".to_string()),
+            },
+            NatRule {
+                rule: 5000,
+                packets: NumberPrefix::Prefixed(Prefix::Kilo, 2.3).into(),
+                bytes: NumberPrefix::Prefixed(Prefix::Kilo, 191.0).into(),
+                nat_type: "MASQ".to_string(),
+                inbound_interface: None,
+                outbound_interface: Some("eth0".to_string()),
+                description: Some("masquerade for WAN".to_string()),
+            },
+            NatRule {
+                rule: 5010,
+                packets: NumberPrefix::Standalone(0.0).into(),
+                bytes: NumberPrefix::Standalone(0.0).into(),
+                nat_type: "SRC".to_string(),
+                inbound_interface: None,
+                outbound_interface: Some("eth1".to_string()),
+                description: None,
+            },
+        ]);
+    }
+}
@@ -0,0 +1,183 @@
+use anyhow::Context;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, multispace0, newline, not_line_ending, space0, space1},
+    combinator::{eof, map, verify},
+    error::Error,
+    multi::many0,
+    number::complete::double,
+    sequence::{delimited, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::system::{Hardware, Sensor},
+    infrastructure::cmd::parser::Parser,
+    service::system::HardwareResult,
+};
+
+pub struct HardwareParser;
+
+enum Reading {
+    Temperature(Sensor),
+    Fan(Sensor),
+}
+
+impl Parser for HardwareParser {
+    type Context<'a> = ();
+    type Item = HardwareResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_hardware(input)
+            .finish()
+            .map(|(_, hardware)| hardware)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse hardware sensors")
+    }
+}
+
+fn parse_hardware(input: &str) -> IResult<&str, HardwareResult> {
+    map(
+        terminated(
+            many0(
+                alt((
+                    map(parse_reading, Some),
+                    map(parse_unavailable, |_| None),
+                    map((space0, newline), |_| None),
+                )),
+            ),
+            (multispace0, eof),
+        ),
+        |readings| {
+            let mut hardware = Hardware::default();
+            for reading in readings.into_iter().flatten() {
+                match reading {
+                    Reading::Temperature(sensor) => hardware.temperatures.push(sensor),
+                    Reading::Fan(sensor) => hardware.fans.push(sensor),
+                }
+            }
+            hardware
+        },
+    ).parse_complete(input)
+}
+
+fn parse_unavailable(input: &str) -> IResult<&str, &str> {
+    verify(
+        terminated(not_line_ending, newline),
+        |line: &str| line.trim_end().ends_with("is not available on this platform"),
+    ).parse_complete(input)
+}
+
+fn parse_reading(input: &str) -> IResult<&str, Reading> {
+    map(
+        terminated(
+            (
+                terminated(map(take_till1(|c| c == ':' || c == '\n'), str::trim), char(':')),
+                delimited(space1, double, space1),
+                alt((tag("C"), tag("RPM"))),
+            ),
+            (space0, newline),
+        ),
+        |(name, value, unit)| {
+            let sensor = Sensor {
+                name: name.to_string(),
+                value,
+            };
+            match unit {
+                "RPM" => Reading::Fan(sensor),
+                _ => Reading::Temperature(sensor),
+            }
+        },
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = HardwareParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Hardware::default());
+    }
+
+    #[test]
+    fn unavailable() {
+        let parser = HardwareParser;
+        let input = indoc! {"
+            Temperature sensor is not available on this platform
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Hardware::default());
+    }
+
+    #[test]
+    fn fan_unavailable() {
+        let parser = HardwareParser;
+        let input = indoc! {"
+            Fan is not available on this platform
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Hardware::default());
+    }
+
+    #[test]
+    fn invalid() {
+        let parser = HardwareParser;
+        let input = indoc! {"
+            Invalid command: show hardware [temperature]
+        "};
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn hardware() {
+        let parser = HardwareParser;
+        let input = indoc! {"
+            CPU Temp:         56.0 C
+            Board Temp(CPU):  44.0 C
+            Board Temp(PHY):  47.5 C
+            PHY Temp:         62.0 C
+            Fan Speed:        3840 RPM
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Hardware {
+            temperatures: vec![
+                Sensor {
+                    name: "CPU Temp".to_string(),
+                    value: 56.0,
+                },
+                Sensor {
+                    name: "Board Temp(CPU)".to_string(),
+                    value: 44.0,
+                },
+                Sensor {
+                    name: "Board Temp(PHY)".to_string(),
+                    value: 47.5,
+                },
+                Sensor {
+                    name: "PHY Temp".to_string(),
+                    value: 62.0,
+                },
+            ],
+            fans: vec![
+                Sensor {
+                    name: "Fan Speed".to_string(),
+                    value: 3840.0,
+                },
+            ],
+        });
+    }
+}
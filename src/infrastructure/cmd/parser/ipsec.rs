@@ -0,0 +1,678 @@
+use anyhow::Context;
+use indexmap::IndexMap;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till, take_till1, take_until, take_while1},
+    character::complete::{alpha1, digit1, line_ending, multispace0, not_line_ending, space0, space1, u32, u64},
+    combinator::{eof, map, map_opt, map_res, opt},
+    error::Error,
+    multi::many0,
+    sequence::{delimited, preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+use serde::{de::{value, IntoDeserializer}, Deserialize};
+
+use crate::{
+    domain::ipsec::{ChildSA, ChildSAState, SA, SAState},
+    infrastructure::cmd::parser::Parser,
+    service::ipsec::IPsecResult,
+};
+
+pub struct IPsecParser;
+
+enum Line {
+    SA(String, u32, SALine),
+    ChildSA(String, u32, ChildSALine),
+    Other,
+}
+
+enum SALine {
+    State {
+        state: SAState,
+        established: Option<u64>,
+        local_host: String,
+        local_id: String,
+        remote_host: String,
+        remote_id: String,
+    },
+    SPIs {
+        version: String,
+        rekey_time: Option<u64>,
+        reauth_time: Option<u64>,
+    },
+    Proposal(Proposal),
+}
+
+enum ChildSALine {
+    State {
+        state: ChildSAState,
+        mode: String,
+        reqid: u32,
+        protocol: String,
+    },
+    Traffic {
+        proposal: Proposal,
+        bytes_in: u64,
+        packets_in: Option<u64>,
+        bytes_out: u64,
+        packets_out: Option<u64>,
+        rekey_time: Option<u64>,
+    },
+    TrafficSelectors {
+        local_ts: Vec<String>,
+        remote_ts: Vec<String>,
+    },
+}
+
+#[derive(Default)]
+struct Proposal {
+    encr_alg: Option<String>,
+    encr_keysize: Option<u32>,
+    integ_alg: Option<String>,
+    prf_alg: Option<String>,
+    dh_group: Option<String>,
+}
+
+impl Parser for IPsecParser {
+    type Context<'a> = ();
+    type Item = IPsecResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_ipsec_status(input)
+            .finish()
+            .map(|(_, lines)| collect_sas(lines))
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse IPsec status")
+    }
+}
+
+fn collect_sas(lines: Vec<Line>) -> IPsecResult {
+    let mut sas = IndexMap::<String, SA>::new();
+    for line in lines {
+        match line {
+            Line::SA(name, uniqueid, SALine::State { state, established, local_host, local_id, remote_host, remote_id }) => {
+                sas.insert(name, SA {
+                    uniqueid,
+                    version: String::new(),
+                    state,
+                    // Ports are not shown in the status output.
+                    local_port: 0,
+                    local_host,
+                    local_id,
+                    remote_host,
+                    remote_port: 0,
+                    remote_id,
+                    remote_xauth_id: None,
+                    remote_eap_id: None,
+                    initiator: None,
+                    encr_alg: None,
+                    encr_keysize: None,
+                    integ_alg: None,
+                    integ_keysize: None,
+                    prf_alg: None,
+                    dh_group: None,
+                    established,
+                    rekey_time: None,
+                    reauth_time: None,
+                    child_sas: IndexMap::new(),
+                });
+            },
+            Line::SA(name, _, line) => {
+                let Some(sa) = sas.get_mut(&name) else {
+                    continue;
+                };
+                match line {
+                    SALine::SPIs { version, rekey_time, reauth_time } => {
+                        sa.version = version;
+                        sa.rekey_time = rekey_time;
+                        sa.reauth_time = reauth_time;
+                    },
+                    SALine::Proposal(proposal) => {
+                        sa.encr_alg = proposal.encr_alg;
+                        sa.encr_keysize = proposal.encr_keysize;
+                        sa.integ_alg = proposal.integ_alg;
+                        sa.prf_alg = proposal.prf_alg;
+                        sa.dh_group = proposal.dh_group;
+                    },
+                    SALine::State { .. } => {},
+                }
+            },
+            Line::ChildSA(name, uniqueid, line) => {
+                // Child SAs are listed right after the IKE SA they belong to.
+                let Some((_, sa)) = sas.last_mut() else {
+                    continue;
+                };
+                let key = format!("{name}-{uniqueid}");
+                if let ChildSALine::State { state, mode, reqid, protocol } = line {
+                    sa.child_sas.insert(key, ChildSA {
+                        name,
+                        uniqueid,
+                        reqid,
+                        state,
+                        mode,
+                        protocol: Some(protocol),
+                        encr_alg: None,
+                        encr_keysize: None,
+                        integ_alg: None,
+                        integ_keysize: None,
+                        prf_alg: None,
+                        dh_group: None,
+                        esn: None,
+                        bytes_in: None,
+                        packets_in: None,
+                        use_in: None,
+                        bytes_out: None,
+                        packets_out: None,
+                        use_out: None,
+                        rekey_time: None,
+                        life_time: None,
+                        install_time: None,
+                        local_ts: vec![],
+                        remote_ts: vec![],
+                    });
+                    continue;
+                }
+
+                let Some(child_sa) = sa.child_sas.get_mut(&key) else {
+                    continue;
+                };
+                match line {
+                    ChildSALine::Traffic { proposal, bytes_in, packets_in, bytes_out, packets_out, rekey_time } => {
+                        child_sa.encr_alg = proposal.encr_alg;
+                        child_sa.encr_keysize = proposal.encr_keysize;
+                        child_sa.integ_alg = proposal.integ_alg;
+                        child_sa.prf_alg = proposal.prf_alg;
+                        child_sa.dh_group = proposal.dh_group;
+                        child_sa.bytes_in = Some(bytes_in);
+                        child_sa.packets_in = Some(packets_in.unwrap_or_default());
+                        child_sa.bytes_out = Some(bytes_out);
+                        child_sa.packets_out = Some(packets_out.unwrap_or_default());
+                        child_sa.rekey_time = rekey_time;
+                    },
+                    ChildSALine::TrafficSelectors { local_ts, remote_ts } => {
+                        child_sa.local_ts = local_ts;
+                        child_sa.remote_ts = remote_ts;
+                    },
+                    ChildSALine::State { .. } => {},
+                }
+            },
+            Line::Other => {},
+        }
+    }
+    sas
+}
+
+fn parse_ipsec_status(input: &str) -> IResult<&str, Vec<Line>> {
+    alt((
+        map(
+            (multispace0, eof),
+            |_| vec![],
+        ),
+        preceded(
+            (take_until("Security Associations"), not_line_ending, line_ending),
+            many0(terminated(parse_line, line_ending)),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line> {
+    alt((
+        map(
+            (
+                preceded(space0, take_till1(|c| c == '[' || c == '{' || c == '\n')),
+                delimited(tag("["), u32, tag("]:")),
+                preceded(space0, parse_sa_line),
+            ),
+            |(name, uniqueid, line)| Line::SA(name.to_string(), uniqueid, line),
+        ),
+        map(
+            (
+                preceded(space0, take_till1(|c| c == '[' || c == '{' || c == '\n')),
+                delimited(tag("{"), u32, tag("}:")),
+                preceded(space0, parse_child_sa_line),
+            ),
+            |(name, uniqueid, line)| Line::ChildSA(name.to_string(), uniqueid, line),
+        ),
+        map(not_line_ending, |_| Line::Other),
+    )).parse_complete(input)
+}
+
+fn parse_sa_line(input: &str) -> IResult<&str, SALine> {
+    alt((
+        map(
+            (
+                map_res(parse_state, deserialize),
+                opt(delimited(space1, parse_time_delta, tag(" ago"))),
+                tag(", "),
+                take_till1(|c| c == '['),
+                delimited(tag("["), take_till(|c| c == ']'), tag("]")),
+                tag("..."),
+                take_till1(|c| c == '['),
+                delimited(tag("["), take_till(|c| c == ']'), tag("]")),
+                not_line_ending,
+            ),
+            |(state, established, _, local_host, local_id, _, remote_host, remote_id, _)| SALine::State {
+                state,
+                established,
+                local_host: local_host.to_string(),
+                local_id: local_id.to_string(),
+                remote_host: remote_host.to_string(),
+                remote_id: remote_id.to_string(),
+            },
+        ),
+        map(
+            (
+                preceded(tag("IKEv"), digit1),
+                tag(" SPIs: "),
+                take_till(|c| c == ',' || c == '\n'),
+                opt(preceded(tag(", "), parse_ike_sa_timer)),
+                not_line_ending,
+            ),
+            |(version, _, _, timer, _)| SALine::SPIs {
+                version: version.to_string(),
+                rekey_time: timer.and_then(|(reauth, time)| (!reauth).then_some(time)),
+                reauth_time: timer.and_then(|(reauth, time)| reauth.then_some(time)),
+            },
+        ),
+        map(
+            preceded(tag("IKE proposal: "), not_line_ending),
+            |proposal| SALine::Proposal(parse_proposal(proposal)),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_ike_sa_timer(input: &str) -> IResult<&str, (bool, u64)> {
+    alt((
+        map(
+            preceded(tag("rekeying in "), parse_time_delta),
+            |time| (false, time),
+        ),
+        map(
+            preceded((take_until("reauthentication in "), tag("reauthentication in ")), parse_time_delta),
+            |time| (true, time),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_child_sa_line(input: &str) -> IResult<&str, ChildSALine> {
+    alt((
+        map(
+            (
+                map_res(parse_state, deserialize),
+                preceded(tag(", "), alpha1),
+                preceded(tag(", reqid "), u32),
+                preceded(tag(", "), alpha1),
+                not_line_ending,
+            ),
+            |(state, mode, reqid, protocol, _)| ChildSALine::State {
+                state,
+                mode: mode.to_string(),
+                reqid,
+                protocol: protocol.to_string(),
+            },
+        ),
+        map(
+            (
+                take_till1(|c| c == ',' || c == '\n'),
+                delimited(tag(", "), u64, tag(" bytes_i")),
+                opt(parse_packets),
+                delimited(tag(", "), u64, tag(" bytes_o")),
+                opt(parse_packets),
+                opt(preceded(tag(", rekeying in "), parse_time_delta)),
+                not_line_ending,
+            ),
+            |(proposal, bytes_in, packets_in, bytes_out, packets_out, rekey_time, _)| ChildSALine::Traffic {
+                proposal: parse_proposal(proposal),
+                bytes_in,
+                packets_in,
+                bytes_out,
+                packets_out,
+                rekey_time,
+            },
+        ),
+        map(
+            (
+                take_until(" === "),
+                tag(" === "),
+                not_line_ending,
+            ),
+            |(local_ts, _, remote_ts): (&str, _, &str)| ChildSALine::TrafficSelectors {
+                local_ts: local_ts.split_whitespace().map(str::to_string).collect(),
+                remote_ts: remote_ts.split_whitespace().map(str::to_string).collect(),
+            },
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_packets(input: &str) -> IResult<&str, u64> {
+    delimited(tag(" ("), u64, (tag(" pkts"), take_till(|c| c == ')'), tag(")"))).parse_complete(input)
+}
+
+fn parse_state(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_ascii_uppercase() || c == '_').parse_complete(input)
+}
+
+fn parse_time_delta(input: &str) -> IResult<&str, u64> {
+    map_opt(
+        (terminated(u64, space1), alpha1),
+        |(value, unit): (u64, &str)| {
+            let seconds = match unit.trim_end_matches('s') {
+                "second" => 1,
+                "minute" => 60,
+                "hour" => 60 * 60,
+                "day" => 24 * 60 * 60,
+                _ => return None,
+            };
+            Some(value * seconds)
+        },
+    ).parse_complete(input)
+}
+
+fn deserialize<'a, T: Deserialize<'a>>(input: &'a str) -> Result<T, value::Error> {
+    T::deserialize(input.into_deserializer())
+}
+
+// Proposals are shown as encryption algorithm with key size followed by integrity algorithm, PRF, and DH group, e.g. AES_CBC_128/HMAC_SHA1_96/PRF_HMAC_SHA1/MODP_2048
+fn parse_proposal(input: &str) -> Proposal {
+    let mut algorithms = input.trim().split('/');
+    let mut proposal = Proposal::default();
+
+    if let Some(encr) = algorithms.next() {
+        let (encr_alg, encr_keysize) = match encr.rsplit_once('_').and_then(|(alg, size)| Some((alg, size.parse().ok()?))) {
+            Some((alg, size)) => (alg, Some(size)),
+            None => (encr, None),
+        };
+        proposal.encr_alg = Some(encr_alg.to_string());
+        proposal.encr_keysize = encr_keysize;
+    }
+
+    for algorithm in algorithms {
+        if algorithm.starts_with("PRF_") {
+            proposal.prf_alg = Some(algorithm.to_string());
+        } else if ["MODP_", "ECP_", "CURVE_"].iter().any(|p| algorithm.starts_with(p)) {
+            proposal.dh_group = Some(algorithm.to_string());
+        } else {
+            proposal.integ_alg = Some(algorithm.to_string());
+        }
+    }
+
+    proposal
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let parser = IPsecParser;
+        let input = "connecting to 'unix:///var/run/charon.ctl' failed: No such file or directory";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn not_running() {
+        let parser = IPsecParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, indexmap! {});
+    }
+
+    #[test]
+    fn empty() {
+        let parser = IPsecParser;
+        let input = indoc! {"
+            Status of IKE charon daemon (strongSwan 5.7.2, Linux 4.9.79-UBNT, mips64):
+              uptime: 3 days, since Oct 14 09:12:41 2026
+              worker threads: 16 of 16 idle, 5/0/0/0 working, job queue: 0/0/0/0, scheduled: 0
+            Listening IP addresses:
+              192.0.2.1
+            Connections:
+            Security Associations (0 up, 0 connecting):
+              none
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, indexmap! {});
+    }
+
+    #[test]
+    fn sas() {
+        let parser = IPsecParser;
+        let input = indoc! {"
+            Status of IKE charon daemon (strongSwan 5.7.2, Linux 4.9.79-UBNT, mips64):
+              uptime: 3 days, since Oct 14 09:12:41 2026
+              worker threads: 11 of 16 idle, 5/0/0/0 working, job queue: 0/0/0/0, scheduled: 6
+              loaded plugins: charon aes sha1 sha2 md5 random nonce x509 pubkey pem openssl hmac gcm kernel-netlink socket-default stroke updown
+            Listening IP addresses:
+              192.0.2.1
+              192.168.1.1
+            Connections:
+            peer-198.51.100.1-tunnel-1:  192.0.2.1...198.51.100.1  IKEv2
+            peer-198.51.100.1-tunnel-1:   local:  [192.0.2.1] uses pre-shared key authentication
+            peer-198.51.100.1-tunnel-1:   remote: [198.51.100.1] uses pre-shared key authentication
+            peer-198.51.100.1-tunnel-1:   child:  192.168.1.0/24 === 10.0.1.0/24 TUNNEL, dpdaction=restart
+            Security Associations (2 up, 1 connecting):
+            peer-198.51.100.1-tunnel-1[3]: ESTABLISHED 25 minutes ago, 192.0.2.1[192.0.2.1]...198.51.100.1[198.51.100.1]
+            peer-198.51.100.1-tunnel-1[3]: IKEv2 SPIs: 8c2c6fe1b0e1a6c2_i* 3f2a5b4e7d6c1a09_r, pre-shared key reauthentication in 2 hours
+            peer-198.51.100.1-tunnel-1[3]: IKE proposal: AES_CBC_256/HMAC_SHA2_256_128/PRF_HMAC_SHA2_256/MODP_2048
+            peer-198.51.100.1-tunnel-1{5}:  INSTALLED, TUNNEL, reqid 1, ESP SPIs: c5b1d2e3_i c9a8b7c6_o
+            peer-198.51.100.1-tunnel-1{5}:  AES_CBC_256/HMAC_SHA2_256_128/MODP_2048, 1234567 bytes_i (8901 pkts, 2s ago), 7654321 bytes_o (10987 pkts, 1s ago), rekeying in 35 minutes
+            peer-198.51.100.1-tunnel-1{5}:   192.168.1.0/24 === 10.0.1.0/24
+            peer-203.0.113.1-tunnel-1[4]: ESTABLISHED 3 hours ago, 192.0.2.1[192.0.2.1]...203.0.113.1[203.0.113.1]
+            peer-203.0.113.1-tunnel-1[4]: IKEv1 SPIs: 5d4c3b2a19081726_i 0123456789abcdef_r*, rekeying in 5 hours
+            peer-203.0.113.1-tunnel-1[4]: IKE proposal: 3DES_CBC/HMAC_SHA1_96/PRF_HMAC_SHA1/MODP_1024
+            peer-203.0.113.1-tunnel-1{6}:  INSTALLED, TUNNEL, reqid 2, ESP in UDP SPIs: c1d2e3f4_i c4f3e2d1_o
+            peer-203.0.113.1-tunnel-1{6}:  3DES_CBC/HMAC_SHA1_96, 0 bytes_i, 0 bytes_o, rekeying in 40 minutes
+            peer-203.0.113.1-tunnel-1{6}:   192.168.1.0/24 === 10.0.2.0/24 10.0.3.0/24
+            peer-2001:db8::1-tunnel-1[5]: CONNECTING, 2001:db8::2[%any]...2001:db8::1[%any]
+            peer-2001:db8::1-tunnel-1[5]: Tasks queued: IKE_VENDOR IKE_INIT IKE_NATD IKE_CERT_PRE IKE_AUTH
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, indexmap! {
+            "peer-198.51.100.1-tunnel-1".to_string() => SA {
+                uniqueid: 3,
+                version: "2".to_string(),
+                state: SAState::Established,
+                local_host: "192.0.2.1".to_string(),
+                local_port: 0,
+                local_id: "192.0.2.1".to_string(),
+                remote_host: "198.51.100.1".to_string(),
+                remote_port: 0,
+                remote_id: "198.51.100.1".to_string(),
+                remote_xauth_id: None,
+                remote_eap_id: None,
+                initiator: None,
+                encr_alg: Some("AES_CBC".to_string()),
+                encr_keysize: Some(256),
+                integ_alg: Some("HMAC_SHA2_256_128".to_string()),
+                integ_keysize: None,
+                prf_alg: Some("PRF_HMAC_SHA2_256".to_string()),
+                dh_group: Some("MODP_2048".to_string()),
+                established: Some(1500),
+                rekey_time: None,
+                reauth_time: Some(7200),
+                child_sas: indexmap! {
+                    "peer-198.51.100.1-tunnel-1-5".to_string() => ChildSA {
+                        name: "peer-198.51.100.1-tunnel-1".to_string(),
+                        uniqueid: 5,
+                        reqid: 1,
+                        state: ChildSAState::Installed,
+                        mode: "TUNNEL".to_string(),
+                        protocol: Some("ESP".to_string()),
+                        encr_alg: Some("AES_CBC".to_string()),
+                        encr_keysize: Some(256),
+                        integ_alg: Some("HMAC_SHA2_256_128".to_string()),
+                        integ_keysize: None,
+                        prf_alg: None,
+                        dh_group: Some("MODP_2048".to_string()),
+                        esn: None,
+                        bytes_in: Some(1234567),
+                        packets_in: Some(8901),
+                        use_in: None,
+                        bytes_out: Some(7654321),
+                        packets_out: Some(10987),
+                        use_out: None,
+                        rekey_time: Some(2100),
+                        life_time: None,
+                        install_time: None,
+                        local_ts: vec!["192.168.1.0/24".to_string()],
+                        remote_ts: vec!["10.0.1.0/24".to_string()],
+                    },
+                },
+            },
+            "peer-203.0.113.1-tunnel-1".to_string() => SA {
+                uniqueid: 4,
+                version: "1".to_string(),
+                state: SAState::Established,
+                local_host: "192.0.2.1".to_string(),
+                local_port: 0,
+                local_id: "192.0.2.1".to_string(),
+                remote_host: "203.0.113.1".to_string(),
+                remote_port: 0,
+                remote_id: "203.0.113.1".to_string(),
+                remote_xauth_id: None,
+                remote_eap_id: None,
+                initiator: None,
+                encr_alg: Some("3DES_CBC".to_string()),
+                encr_keysize: None,
+                integ_alg: Some("HMAC_SHA1_96".to_string()),
+                integ_keysize: None,
+                prf_alg: Some("PRF_HMAC_SHA1".to_string()),
+                dh_group: Some("MODP_1024".to_string()),
+                established: Some(10800),
+                rekey_time: Some(18000),
+                reauth_time: None,
+                child_sas: indexmap! {
+                    "peer-203.0.113.1-tunnel-1-6".to_string() => ChildSA {
+                        name: "peer-203.0.113.1-tunnel-1".to_string(),
+                        uniqueid: 6,
+                        reqid: 2,
+                        state: ChildSAState::Installed,
+                        mode: "TUNNEL".to_string(),
+                        protocol: Some("ESP".to_string()),
+                        encr_alg: Some("3DES_CBC".to_string()),
+                        encr_keysize: None,
+                        integ_alg: Some("HMAC_SHA1_96".to_string()),
+                        integ_keysize: None,
+                        prf_alg: None,
+                        dh_group: None,
+                        esn: None,
+                        bytes_in: Some(0),
+                        packets_in: Some(0),
+                        use_in: None,
+                        bytes_out: Some(0),
+                        packets_out: Some(0),
+                        use_out: None,
+                        rekey_time: Some(2400),
+                        life_time: None,
+                        install_time: None,
+                        local_ts: vec!["192.168.1.0/24".to_string()],
+                        remote_ts: vec!["10.0.2.0/24".to_string(), "10.0.3.0/24".to_string()],
+                    },
+                },
+            },
+            "peer-2001:db8::1-tunnel-1".to_string() => SA {
+                uniqueid: 5,
+                version: String::new(),
+                state: SAState::Connecting,
+                local_host: "2001:db8::2".to_string(),
+                local_port: 0,
+                local_id: "%any".to_string(),
+                remote_host: "2001:db8::1".to_string(),
+                remote_port: 0,
+                remote_id: "%any".to_string(),
+                remote_xauth_id: None,
+                remote_eap_id: None,
+                initiator: None,
+                encr_alg: None,
+                encr_keysize: None,
+                integ_alg: None,
+                integ_keysize: None,
+                prf_alg: None,
+                dh_group: None,
+                established: None,
+                rekey_time: None,
+                reauth_time: None,
+                child_sas: indexmap! {},
+            },
+        });
+    }
+
+    #[test]
+    fn padded() {
+        let parser = IPsecParser;
+        let input = indoc! {"
+            Status of IKE charon daemon (strongSwan 5.7.2, Linux 4.9.79-UBNT, mips64):
+            Security Associations (1 up, 0 connecting):
+                    peer[7]: ESTABLISHED 5 minutes ago, 192.0.2.1[192.0.2.1]...192.0.2.254[192.0.2.254]
+                    peer[7]: IKEv2 SPIs: 0a1b2c3d4e5f6071_i* 8192a3b4c5d6e7f8_r, rekeying in 3 hours
+                    peer[7]: IKE proposal: AES_GCM_16_128/PRF_HMAC_SHA2_256/ECP_256
+                    peer{9}:  INSTALLED, TUNNEL, reqid 3, ESP SPIs: c0a80101_i c0a80102_o
+                    peer{9}:  AES_GCM_16_128, 0 bytes_i, 0 bytes_o, rekeying in 50 minutes
+                    peer{9}:   192.168.1.0/24 === 10.0.4.0/24
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, indexmap! {
+            "peer".to_string() => SA {
+                uniqueid: 7,
+                version: "2".to_string(),
+                state: SAState::Established,
+                local_host: "192.0.2.1".to_string(),
+                local_port: 0,
+                local_id: "192.0.2.1".to_string(),
+                remote_host: "192.0.2.254".to_string(),
+                remote_port: 0,
+                remote_id: "192.0.2.254".to_string(),
+                remote_xauth_id: None,
+                remote_eap_id: None,
+                initiator: None,
+                encr_alg: Some("AES_GCM_16".to_string()),
+                encr_keysize: Some(128),
+                integ_alg: None,
+                integ_keysize: None,
+                prf_alg: Some("PRF_HMAC_SHA2_256".to_string()),
+                dh_group: Some("ECP_256".to_string()),
+                established: Some(300),
+                rekey_time: Some(10800),
+                reauth_time: None,
+                child_sas: indexmap! {
+                    "peer-9".to_string() => ChildSA {
+                        name: "peer".to_string(),
+                        uniqueid: 9,
+                        reqid: 3,
+                        state: ChildSAState::Installed,
+                        mode: "TUNNEL".to_string(),
+                        protocol: Some("ESP".to_string()),
+                        encr_alg: Some("AES_GCM_16".to_string()),
+                        encr_keysize: Some(128),
+                        integ_alg: None,
+                        integ_keysize: None,
+                        prf_alg: None,
+                        dh_group: None,
+                        esn: None,
+                        bytes_in: Some(0),
+                        packets_in: Some(0),
+                        use_in: None,
+                        bytes_out: Some(0),
+                        packets_out: Some(0),
+                        use_out: None,
+                        rekey_time: Some(3000),
+                        life_time: None,
+                        install_time: None,
+                        local_ts: vec!["192.168.1.0/24".to_string()],
+                        remote_ts: vec!["10.0.4.0/24".to_string()],
+                    },
+                },
+            },
+        });
+    }
+}
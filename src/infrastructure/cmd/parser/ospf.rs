@@ -0,0 +1,656 @@
+use std::{net::Ipv4Addr, str::FromStr, time};
+
+use anyhow::Context;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1, take_until},
+    character::complete::{multispace0, newline, not_line_ending, space0, space1, u32, u64},
+    combinator::{eof, map, map_parser, map_res, opt, peek, verify},
+    error::Error,
+    multi::many0,
+    sequence::{delimited, preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::ospf::{OSPFArea, OSPFInterface, OSPFNeighbor},
+    infrastructure::cmd::parser::{Duration, Parser},
+    service::ospf::{OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult},
+};
+
+pub struct OSPFAreaParser;
+
+pub struct OSPFInterfaceParser;
+
+pub struct OSPFNeighborParser;
+
+pub struct OSPF6NeighborParser;
+
+enum AreaLine {
+    Area(String),
+    SpfRuns(u64),
+    Lsas(u64),
+    Other,
+}
+
+enum InterfaceLine {
+    Interface(String),
+    Area(String),
+    Cost(u32),
+    State(String),
+    Other,
+}
+
+impl Parser for OSPFAreaParser {
+    type Context<'a> = ();
+    type Item = OSPFAreaResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_ospf_areas(input)
+            .finish()
+            .map(|(_, areas)| areas)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse OSPF areas")
+    }
+}
+
+impl Parser for OSPFInterfaceParser {
+    type Context<'a> = ();
+    type Item = OSPFInterfaceResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_ospf_interfaces(input)
+            .finish()
+            .map(|(_, interfaces)| interfaces)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse OSPF interfaces")
+    }
+}
+
+impl Parser for OSPFNeighborParser {
+    type Context<'a> = ();
+    type Item = OSPFNeighborResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_ospf_neighbors(input)
+            .finish()
+            .map(|(_, neighbors)| neighbors)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse OSPF neighbors")
+    }
+}
+
+impl Parser for OSPF6NeighborParser {
+    type Context<'a> = ();
+    type Item = OSPFNeighborResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_ospf6_neighbors(input)
+            .finish()
+            .map(|(_, neighbors)| neighbors)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse OSPFv3 neighbors")
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, &str> {
+    terminated(not_line_ending, newline).parse_complete(input)
+}
+
+fn parse_word(input: &str) -> IResult<&str, &str> {
+    take_till1(|c: char| c.is_ascii_whitespace()).parse_complete(input)
+}
+
+fn parse_router_id(input: &str) -> IResult<&str, &str> {
+    verify(parse_word, |s: &str| Ipv4Addr::from_str(s).is_ok()).parse_complete(input)
+}
+
+fn parse_attribute<'a>(key: &'static str) -> impl nom::Parser<&'a str, Output = &'a str, Error = Error<&'a str>> {
+    preceded(
+        (take_until(key), tag(key)),
+        take_till1(|c: char| c == ',' || c.is_ascii_whitespace()),
+    )
+}
+
+fn parse_state(state: &str) -> (String, Option<String>) {
+    match state.split_once('/') {
+        Some((state, "-")) => (state.to_string(), None),
+        Some((state, role)) => (state.to_string(), Some(role.to_string())),
+        None => (state.to_string(), None),
+    }
+}
+
+fn parse_dead_time(dead_time: &str) -> Option<time::Duration> {
+    Duration::from_str(dead_time)
+        .map(Duration::into)
+        .ok()
+        .or_else(|| {
+            dead_time
+                .strip_suffix('s')
+                .and_then(|s| s.parse().ok())
+                .map(time::Duration::from_secs_f64)
+        })
+}
+
+fn parse_empty<T>(input: &str) -> IResult<&str, Vec<T>> {
+    map(
+        (opt((space0, tag("% OSPF instance not found"), parse_line)), multispace0, eof),
+        |_| vec![],
+    ).parse_complete(input)
+}
+
+fn parse_ospf_areas(input: &str) -> IResult<&str, OSPFAreaResult> {
+    alt((
+        parse_empty,
+        preceded(
+            (multispace0, peek(alt((tag("Routing Process"), tag("OSPF Routing Process"))))),
+            map(
+                many0(
+                    alt((
+                        map(
+                            delimited(
+                                (space0, tag("Area"), opt(tag(" ID:")), space1),
+                                parse_router_id,
+                                parse_line,
+                            ),
+                            |area| AreaLine::Area(area.to_string()),
+                        ),
+                        map(
+                            delimited(
+                                (space0, tag("SPF algorithm executed"), space1),
+                                u64,
+                                parse_line,
+                            ),
+                            AreaLine::SpfRuns,
+                        ),
+                        map(
+                            delimited(
+                                (space0, tag("Number of LSA"), space1),
+                                u64,
+                                parse_line,
+                            ),
+                            AreaLine::Lsas,
+                        ),
+                        map(parse_line, |_| AreaLine::Other),
+                    )),
+                ),
+                |lines| {
+                    let mut areas: Vec<OSPFArea> = vec![];
+                    for line in lines {
+                        match (line, areas.last_mut()) {
+                            (AreaLine::Area(area), _) => areas.push(OSPFArea {
+                                area,
+                                lsas: 0,
+                                spf_runs: 0,
+                            }),
+                            (AreaLine::SpfRuns(spf_runs), Some(area)) => area.spf_runs = spf_runs,
+                            (AreaLine::Lsas(lsas), Some(area)) => area.lsas = lsas,
+                            _ => {},
+                        }
+                    }
+                    areas
+                },
+            ),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_ospf_interfaces(input: &str) -> IResult<&str, OSPFInterfaceResult> {
+    alt((
+        parse_empty,
+        preceded(
+            (multispace0, peek(terminated(parse_word, tag(" is ")))),
+            map(
+                many0(
+                    alt((
+                        map(
+                            terminated(parse_word, (tag(" is "), parse_line)),
+                            |interface| InterfaceLine::Interface(interface.to_string()),
+                        ),
+                        map_parser(
+                            parse_line,
+                            alt((
+                                map(parse_attribute(", Area "), |area| InterfaceLine::Area(area.to_string())),
+                                map(map_res(parse_attribute(", Cost: "), str::parse), InterfaceLine::Cost),
+                                map(parse_attribute(", State "), |state| InterfaceLine::State(state.to_string())),
+                            )),
+                        ),
+                        map(parse_line, |_| InterfaceLine::Other),
+                    )),
+                ),
+                |lines| {
+                    let mut interfaces: Vec<(String, Vec<InterfaceLine>)> = vec![];
+                    for line in lines {
+                        match (line, interfaces.last_mut()) {
+                            (InterfaceLine::Interface(interface), _) => interfaces.push((interface, vec![])),
+                            (line, Some((_, attributes))) => attributes.push(line),
+                            _ => {},
+                        }
+                    }
+                    interfaces
+                        .into_iter()
+                        .filter_map(|(interface, attributes)| {
+                            let mut area = None;
+                            let mut cost = None;
+                            let mut state = None;
+                            for attribute in attributes {
+                                match attribute {
+                                    InterfaceLine::Area(a) => { area.get_or_insert(a); },
+                                    InterfaceLine::Cost(c) => cost = Some(c),
+                                    InterfaceLine::State(s) => state = Some(s),
+                                    _ => {},
+                                }
+                            }
+                            Some(OSPFInterface {
+                                interface,
+                                area: area?,
+                                cost: cost?,
+                                state,
+                            })
+                        })
+                        .collect()
+                },
+            ),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_ospf_neighbors(input: &str) -> IResult<&str, OSPFNeighborResult> {
+    alt((
+        parse_empty,
+        preceded(
+            (
+                many0(terminated(opt((tag("OSPF process "), u32, tag(":"))), (space0, newline))),
+                peek((space0, tag("Neighbor ID"))),
+            ),
+            map(
+                many0(
+                    alt((
+                        map(
+                            (
+                                terminated(parse_router_id, space1),
+                                terminated(u32, space1),
+                                terminated(map(parse_word, parse_state), space1),
+                                terminated(map(parse_word, parse_dead_time), space1),
+                                terminated(map_res(parse_word, str::parse), space1),
+                                terminated(parse_word, parse_line),
+                            ),
+                            |(neighbor_id, priority, (state, role), dead_time, address, interface)| {
+                                // Quagga appends the local address to the interface name.
+                                let interface = interface.split_once(':').map_or(interface, |(interface, _)| interface);
+                                Some(OSPFNeighbor {
+                                    neighbor_id: neighbor_id.to_string(),
+                                    priority,
+                                    state,
+                                    role,
+                                    dead_time,
+                                    address: Some(address),
+                                    interface: interface.to_string(),
+                                })
+                            },
+                        ),
+                        map(parse_line, |_| None),
+                    )),
+                ),
+                |neighbors| neighbors.into_iter().flatten().collect(),
+            ),
+        ),
+    )).parse_complete(input)
+}
+
+fn parse_ospf6_neighbors(input: &str) -> IResult<&str, OSPFNeighborResult> {
+    alt((
+        parse_empty,
+        preceded(
+            (multispace0, peek(tag("Neighbor ID"))),
+            map(
+                many0(
+                    alt((
+                        map(
+                            (
+                                terminated(parse_router_id, space1),
+                                terminated(u32, space1),
+                                terminated(map(parse_word, parse_dead_time), space1),
+                                terminated(map(parse_word, parse_state), space1),
+                                terminated(parse_word, space1),
+                                terminated(parse_word, parse_line),
+                            ),
+                            |(neighbor_id, priority, dead_time, (state, role), _, interface)| {
+                                let interface = interface.split_once('[').map_or(interface, |(interface, _)| interface);
+                                Some(OSPFNeighbor {
+                                    neighbor_id: neighbor_id.to_string(),
+                                    priority,
+                                    state,
+                                    role,
+                                    dead_time,
+                                    address: None,
+                                    interface: interface.to_string(),
+                                })
+                            },
+                        ),
+                        map(parse_line, |_| None),
+                    )),
+                ),
+                |neighbors| neighbors.into_iter().flatten().collect(),
+            ),
+        ),
+    )).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{IpAddr, Ipv4Addr},
+        time::Duration,
+    };
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn areas_invalid() {
+        let parser = OSPFAreaParser;
+        let input = "% Unknown command.\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn areas() {
+        let parser = OSPFAreaParser;
+        let input = indoc! {r#"
+             Routing Process "ospf 0" with ID 192.0.2.1
+             Process uptime is 4 days 2 hours 5 minutes
+             Supports only single TOS(TOS0) route
+             This implementation conforms to RFC2328
+             RFC1583Compatibility flag is disabled
+             SPF schedule delay min 0.500 secs, SPF schedule delay max 50.0 secs
+             Refresh timer 10 secs
+             Number of incoming current DD exchange neighbors 0/5
+             Number of outgoing current DD exchange neighbors 0/5
+             Number of external LSA 2. Checksum 0x00f2a1
+             Number of opaque AS LSA 0. Checksum 0x000000
+             Number of non-default external LSA 2
+             External LSA database is unlimited.
+             Number of LSA originated 4
+             Number of LSA received 120
+             Number of areas attached to this router: 2
+                Area 0.0.0.0 (BACKBONE)
+                    Number of interfaces in this area is 2(2)
+                    Number of fully adjacent neighbors in this area is 1
+                    Area has no authentication
+                    SPF algorithm last executed 00:10:20.123 ago
+                    SPF algorithm executed 5 times
+                    Number of LSA 6. Checksum 0x02e6c2
+                Area 0.0.0.1
+                    Number of interfaces in this area is 1(1)
+                    Number of fully adjacent neighbors in this area is 0
+                    Area has no authentication
+                    SPF algorithm executed 2 times
+                    Number of LSA 3. Checksum 0x01a2b3
+        "#};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFArea {
+                area: "0.0.0.0".to_string(),
+                lsas: 6,
+                spf_runs: 5,
+            },
+            OSPFArea {
+                area: "0.0.0.1".to_string(),
+                lsas: 3,
+                spf_runs: 2,
+            },
+        ]);
+    }
+
+    #[test]
+    fn areas_quagga() {
+        let parser = OSPFAreaParser;
+        let input = indoc! {"
+             OSPF Routing Process, Router ID: 192.0.2.1
+             Supports only single TOS (TOS0) routes
+             This implementation conforms to RFC2328
+             Number of external LSA 0. Checksum Sum 0x00000000
+             Number of areas attached to this router: 1
+
+             Area ID: 0.0.0.0 (Backbone)
+               Number of interfaces in this area: Total: 2, Active: 2
+               Number of fully adjacent neighbors in this area: 1
+               Area has no authentication
+               SPF algorithm executed 7 times
+               Number of LSA 4
+               Number of router LSA 2. Checksum Sum 0x0000e5c8
+
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFArea {
+                area: "0.0.0.0".to_string(),
+                lsas: 4,
+                spf_runs: 7,
+            },
+        ]);
+    }
+
+    #[test]
+    fn areas_empty() {
+        let parser = OSPFAreaParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn interfaces_invalid() {
+        let parser = OSPFInterfaceParser;
+        let input = "% Unknown command.\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn interfaces() {
+        let parser = OSPFInterfaceParser;
+        let input = indoc! {"
+            eth0 is up, line protocol is up
+              Internet Address 10.0.0.1/24, Area 0.0.0.0, MTU 1500
+              Process ID 0, Router ID 192.0.2.1, Network Type BROADCAST, Cost: 10
+              Transmit Delay is 1 sec, State DR, Priority 1, TE Metric 10
+              Designated Router (ID) 192.0.2.1, Interface Address 10.0.0.1
+              Backup Designated Router (ID) 192.0.2.2, Interface Address 10.0.0.2
+              Timer intervals configured, Hello 10, Dead 40, Wait 40, Retransmit 5
+                Hello due in 00:00:03
+              Neighbor Count is 1, Adjacent neighbor count is 1
+            eth1 is up, line protocol is up
+              OSPF not enabled on this interface
+            lo is up, line protocol is up
+              OSPF not enabled on this interface
+            pppoe0 is up, line protocol is up
+              Internet Address 203.0.113.1/32, Area 0.0.0.1, MTU 1492
+              Process ID 0, Router ID 192.0.2.1, Network Type POINTOPOINT, Cost: 100
+              Transmit Delay is 1 sec, State Point-To-Point, Priority 1, TE Metric 100
+              Timer intervals configured, Hello 10, Dead 40, Wait 40, Retransmit 5
+                Hello due in 00:00:07
+              Neighbor Count is 0, Adjacent neighbor count is 0
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFInterface {
+                interface: "eth0".to_string(),
+                area: "0.0.0.0".to_string(),
+                cost: 10,
+                state: Some("DR".to_string()),
+            },
+            OSPFInterface {
+                interface: "pppoe0".to_string(),
+                area: "0.0.0.1".to_string(),
+                cost: 100,
+                state: Some("Point-To-Point".to_string()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn interfaces_quagga() {
+        let parser = OSPFInterfaceParser;
+        let input = indoc! {"
+            eth0 is up
+              ifindex 2, MTU 1500 bytes, BW 0 Kbit <UP,BROADCAST,RUNNING,MULTICAST>
+              Internet Address 10.0.0.1/24, Broadcast 10.0.0.255, Area 0.0.0.0
+              MTU mismatch detection:enabled
+              Router ID 192.0.2.1, Network Type BROADCAST, Cost: 10
+              Transmit Delay is 1 sec, State Backup, Priority 1
+              Designated Router (ID) 192.0.2.2, Interface Address 10.0.0.2
+              Backup Designated Router (ID) 192.0.2.1, Interface Address 10.0.0.1
+              Multicast group memberships: OSPFAllRouters OSPFDesignatedRouters
+              Timer intervals configured, Hello 10s, Dead 40s, Wait 40s, Retransmit 5
+                Hello due in 2.345s
+              Neighbor Count is 1, Adjacent neighbor count is 1
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFInterface {
+                interface: "eth0".to_string(),
+                area: "0.0.0.0".to_string(),
+                cost: 10,
+                state: Some("Backup".to_string()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn neighbors_invalid() {
+        let parser = OSPFNeighborParser;
+        let input = "% Unknown command.\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let parser = OSPFNeighborParser;
+        let input = indoc! {"
+
+            OSPF process 0:
+            Neighbor ID     Pri   State            Dead Time   Address         Interface           Instance ID
+            192.0.2.2         1   Full/DR          00:00:37    10.0.0.2        eth0                    0
+            192.0.2.3         1   2-Way/DROther    00:00:33    10.0.0.3        eth0                    0
+            192.0.2.4         0   Full/-           00:00:39    10.0.1.2        pppoe0                  0
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFNeighbor {
+                neighbor_id: "192.0.2.2".to_string(),
+                priority: 1,
+                state: "Full".to_string(),
+                role: Some("DR".to_string()),
+                dead_time: Some(Duration::new(37, 0)),
+                address: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2))),
+                interface: "eth0".to_string(),
+            },
+            OSPFNeighbor {
+                neighbor_id: "192.0.2.3".to_string(),
+                priority: 1,
+                state: "2-Way".to_string(),
+                role: Some("DROther".to_string()),
+                dead_time: Some(Duration::new(33, 0)),
+                address: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3))),
+                interface: "eth0".to_string(),
+            },
+            OSPFNeighbor {
+                neighbor_id: "192.0.2.4".to_string(),
+                priority: 0,
+                state: "Full".to_string(),
+                role: None,
+                dead_time: Some(Duration::new(39, 0)),
+                address: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 1, 2))),
+                interface: "pppoe0".to_string(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn neighbors_quagga() {
+        let parser = OSPFNeighborParser;
+        let input = indoc! {"
+
+                Neighbor ID Pri State           Dead Time Address         Interface            RXmtL RqstL DBsmL
+            192.0.2.2         1 Full/DR           38.125s 10.0.0.2        eth0:10.0.0.1            0     0     0
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFNeighbor {
+                neighbor_id: "192.0.2.2".to_string(),
+                priority: 1,
+                state: "Full".to_string(),
+                role: Some("DR".to_string()),
+                dead_time: Some(Duration::from_millis(38125)),
+                address: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2))),
+                interface: "eth0".to_string(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn neighbors_empty() {
+        let parser = OSPFNeighborParser;
+        let input = "% OSPF instance not found\n";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn ospf6_neighbors_invalid() {
+        let parser = OSPF6NeighborParser;
+        let input = "% Unknown command.\n";
+
+        let actual = parser.parse(input, ());
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn ospf6_neighbors() {
+        let parser = OSPF6NeighborParser;
+        let input = indoc! {"
+            Neighbor ID     Pri    DeadTime  State/IfState         Duration I/F[State]
+            192.0.2.2         1    00:00:35   Full/DR              01:23:45 eth0[BDR]
+            192.0.2.3         1    00:00:31   2-Way/DROther        01:23:40 eth0[BDR]
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            OSPFNeighbor {
+                neighbor_id: "192.0.2.2".to_string(),
+                priority: 1,
+                state: "Full".to_string(),
+                role: Some("DR".to_string()),
+                dead_time: Some(Duration::new(35, 0)),
+                address: None,
+                interface: "eth0".to_string(),
+            },
+            OSPFNeighbor {
+                neighbor_id: "192.0.2.3".to_string(),
+                priority: 1,
+                state: "2-Way".to_string(),
+                role: Some("DROther".to_string()),
+                dead_time: Some(Duration::new(31, 0)),
+                address: None,
+                interface: "eth0".to_string(),
+            },
+        ]);
+    }
+}
@@ -1,12 +1,19 @@
-use std::{fmt::{self, Write}, future::Future};
+use std::{error, fmt::{self, Write}, future::Future, time::Duration};
 
-use anyhow::{anyhow, Context};
+use anyhow::Context;
+use derive_more::Constructor;
 use indenter::indented;
-use tokio::process::Command;
+use tokio::{process::Command, time::timeout};
 
 pub mod bgp;
 pub mod ddns;
+pub mod dhcp;
+pub mod firewall;
+pub mod hardware;
+pub mod interface;
+pub mod ipsec;
 pub mod load_balance;
+pub mod ospf;
 pub mod pppoe;
 pub mod version;
 
@@ -21,27 +28,56 @@ mockall::mock! {
 }
 
 pub trait Executor {
-    fn output(&self, command: &str, args: &[&str]) -> impl Future<Output = anyhow::Result<String>> + Send {
-        log::debug!("executing {command} with {args:?}");
+    fn output(&self, command: &str, args: &[&str]) -> impl Future<Output = anyhow::Result<String>> + Send;
+}
 
-        async move {
-            let output = Command::new(command).args(args).output().await.context(format!("error executing {command} with {args:?}"))?;
-            if !output.status.success() {
-                let stdout = Output(&output.stdout);
-                let stderr = Output(&output.stderr);
-                let result = match output.status.code() {
-                    Some(code) => Err(anyhow!("Process exited with {code}")),
-                    None => Err(anyhow!("Process terminated by signal")),
-                };
-                return result.context(format!("error executing {command} with {args:?}\nStdout:{stdout:?}\nStderr:{stderr:?}"));
-            }
+#[derive(Constructor, Debug)]
+pub struct TimeoutError {
+    command: String,
+    args: Vec<String>,
+    timeout: Duration,
+}
 
-            let result = String::from_utf8(output.stdout)?;
-            Ok(result)
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out executing {} with {:?} after {:?}", self.command, self.args, self.timeout)
+    }
+}
+
+impl error::Error for TimeoutError {}
+
+#[derive(Constructor, Debug)]
+pub struct ExitError {
+    code: Option<i32>,
+    stdout: String,
+    stderr: String,
+}
+
+impl ExitError {
+    pub fn code(&self) -> Option<i32> {
+        self.code
+    }
+
+    pub fn stdout(&self) -> &str {
+        &self.stdout
+    }
+
+    pub fn stderr(&self) -> &str {
+        &self.stderr
+    }
+}
+
+impl fmt::Display for ExitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "Process exited with {code}"),
+            None => write!(f, "Process terminated by signal"),
         }
     }
 }
 
+impl error::Error for ExitError {}
+
 struct Output<'a>(&'a [u8]);
 
 impl fmt::Debug for Output<'_> {
@@ -57,6 +93,76 @@ impl fmt::Debug for Output<'_> {
     }
 }
 
-pub struct CommandExecutor;
+#[derive(Clone, Constructor, Copy)]
+pub struct CommandExecutor {
+    timeout: Option<Duration>,
+}
 
-impl Executor for CommandExecutor {}
+impl Executor for CommandExecutor {
+    fn output(&self, command: &str, args: &[&str]) -> impl Future<Output = anyhow::Result<String>> + Send {
+        log::debug!("executing {command} with {args:?}");
+
+        async move {
+            let mut child = Command::new(command);
+            child.args(args).kill_on_drop(true);
+
+            let output = match self.timeout {
+                Some(duration) => timeout(duration, child.output()).await.map_err(|_| TimeoutError {
+                    command: command.to_string(),
+                    args: args.iter().map(|a| a.to_string()).collect(),
+                    timeout: duration,
+                })?,
+                None => child.output().await,
+            };
+
+            let output = output.context(format!("error executing {command} with {args:?}"))?;
+            if !output.status.success() {
+                let stdout = Output(&output.stdout);
+                let stderr = Output(&output.stderr);
+                let context = format!("error executing {command} with {args:?}\nStdout:{stdout:?}\nStderr:{stderr:?}");
+                let e = ExitError::new(
+                    output.status.code(),
+                    String::from_utf8_lossy(&output.stdout).into_owned(),
+                    String::from_utf8_lossy(&output.stderr).into_owned(),
+                );
+                return Err(e).context(context);
+            }
+
+            let result = String::from_utf8(output.stdout)?;
+            Ok(result)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn output() {
+        let executor = CommandExecutor::new(Some(Duration::from_secs(5)));
+
+        let actual = executor.output("echo", &["-n", "hello"]).await.unwrap();
+        assert_eq!(actual, "hello");
+    }
+
+    #[tokio::test]
+    async fn output_exit() {
+        let executor = CommandExecutor::new(Some(Duration::from_secs(5)));
+
+        let actual = executor.output("sh", &["-c", "echo hello; exit 3"]).await.unwrap_err();
+        let actual = actual.downcast_ref::<ExitError>().unwrap();
+        assert_eq!(actual.code(), Some(3));
+        assert_eq!(actual.stdout(), "hello\n");
+    }
+
+    #[tokio::test]
+    async fn output_timeout() {
+        let executor = CommandExecutor::new(Some(Duration::from_millis(100)));
+
+        let actual = executor.output("sleep", &["10"]).await.unwrap_err();
+        assert!(actual.is::<TimeoutError>());
+    }
+}
@@ -1,74 +1,193 @@
-use tokio::try_join;
+use std::{collections::HashMap, sync::OnceLock};
+
+use tokio::{join, try_join};
 
 use crate::{
+    domain::bgp::BGPTable,
     infrastructure::{
         cmd::{parser::Parser, runner::Executor},
-        config::env::VtyshCommand,
+        config::env::{BGPFormat, VtyshCommand},
+    },
+    service::{
+        bgp::{BGPNeighborDetailResult, BGPStatusResult, BGPTableResult, BGPVrfNeighborDetailResult},
+        version::VersionResult,
+        Runner,
     },
-    service::{bgp::BGPStatusResult, Runner},
 };
 
-pub struct BGPRunner<E, P> {
+const DEFAULT_VRF: &str = "default";
+
+pub struct BGPRunner<E, P, D, J, K, V> {
     command: VtyshCommand,
+    format: BGPFormat,
+    detected_format: OnceLock<BGPFormat>,
     executor: E,
     parser: P,
+    detail_parser: D,
+    json_parser: J,
+    detail_json_parser: K,
+    version_runner: V,
 }
 
-impl<E, P> BGPRunner<E, P>
+impl<E, P, D, J, K, V> BGPRunner<E, P, D, J, K, V>
 where
     E: Executor + Send + Sync,
     P: Parser<Context<'static> = (), Item = BGPStatusResult> + Send + Sync,
+    D: Parser<Context<'static> = (), Item = BGPNeighborDetailResult> + Send + Sync,
+    J: Parser<Context<'static> = (), Item = BGPTableResult> + Send + Sync,
+    K: Parser<Context<'static> = (), Item = BGPVrfNeighborDetailResult> + Send + Sync,
+    V: Runner<Item = VersionResult> + Send + Sync,
 {
-    pub fn new(command: VtyshCommand, executor: E, parser: P) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        command: VtyshCommand,
+        format: BGPFormat,
+        executor: E,
+        parser: P,
+        detail_parser: D,
+        json_parser: J,
+        detail_json_parser: K,
+        version_runner: V,
+    ) -> Self {
         Self {
             command,
+            format,
+            detected_format: OnceLock::new(),
             executor,
             parser,
+            detail_parser,
+            json_parser,
+            detail_json_parser,
+            version_runner,
+        }
+    }
+
+    async fn format(&self) -> BGPFormat {
+        if self.format != BGPFormat::Auto {
+            return self.format;
+        }
+        if let Some(&format) = self.detected_format.get() {
+            return format;
+        }
+
+        // EdgeOS v3 and later ship FRR instead of ZebOS.
+        match self.version_runner.run().await {
+            Ok(version) => {
+                let major = version.version
+                    .trim_start_matches('v')
+                    .split('.')
+                    .next()
+                    .and_then(|major| major.parse::<u32>().ok());
+                let format = match major {
+                    Some(major) if major >= 3 => BGPFormat::Json,
+                    _ => BGPFormat::Text,
+                };
+                *self.detected_format.get_or_init(|| format)
+            },
+            Err(e) => {
+                log::debug!("failed to detect output format of vtysh: {e:?}");
+                BGPFormat::Text
+            },
         }
     }
 
     async fn ipv4(&self) -> anyhow::Result<BGPStatusResult> {
         let output = self.executor.output(&self.command, &["-c", "show ip bgp summary"]).await?;
-        let result = self.parser.parse(&output, ())?.map(|mut status| {
-            status.neighbors.retain(|n| n.neighbor.is_ipv4());
-            status
-        });
+        let result = self.parser.parse(&output, ())?;
         Ok(result)
     }
 
     async fn ipv6(&self) -> anyhow::Result<BGPStatusResult> {
         let output = self.executor.output(&self.command, &["-c", "show bgp ipv6 summary"]).await?;
-        let result = self.parser.parse(&output, ())?.map(|mut status| {
-            status.neighbors.retain(|n| n.neighbor.is_ipv6());
-            status
-        });
+        let result = self.parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn text(&self) -> anyhow::Result<BGPTableResult> {
+        // ZebOS supports neither VRFs nor address families other than IPv4 and IPv6 unicast.
+        let (ipv4, ipv6) = try_join!(self.ipv4(), self.ipv6())?;
+        let result = [("ipv4", ipv4), ("ipv6", ipv6)]
+            .into_iter()
+            .filter_map(|(afi, status)| Some(BGPTable {
+                vrf: DEFAULT_VRF.to_string(),
+                afi: afi.to_string(),
+                safi: "unicast".to_string(),
+                status: status?,
+            }))
+            .collect();
+        Ok(result)
+    }
+
+    async fn json(&self) -> anyhow::Result<BGPTableResult> {
+        let output = self.executor.output(&self.command, &["-c", "show bgp vrf all summary json"]).await?;
+        let result = self.json_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn details_text(&self) -> anyhow::Result<BGPVrfNeighborDetailResult> {
+        let output = self.executor.output(&self.command, &["-c", "show ip bgp neighbors"]).await?;
+        let result = self.detail_parser.parse(&output, ())?;
+        Ok(result.into_iter().map(|d| (DEFAULT_VRF.to_string(), d)).collect())
+    }
+
+    async fn details_json(&self) -> anyhow::Result<BGPVrfNeighborDetailResult> {
+        let output = self.executor.output(&self.command, &["-c", "show bgp vrf all neighbors json"]).await?;
+        let result = self.detail_json_parser.parse(&output, ())?;
         Ok(result)
     }
 }
 
-impl<E, P> Runner for BGPRunner<E, P>
+impl<E, P, D, J, K, V> Runner for BGPRunner<E, P, D, J, K, V>
 where
     E: Executor + Send + Sync,
     P: Parser<Context<'static> = (), Item = BGPStatusResult> + Send + Sync,
+    D: Parser<Context<'static> = (), Item = BGPNeighborDetailResult> + Send + Sync,
+    J: Parser<Context<'static> = (), Item = BGPTableResult> + Send + Sync,
+    K: Parser<Context<'static> = (), Item = BGPVrfNeighborDetailResult> + Send + Sync,
+    V: Runner<Item = VersionResult> + Send + Sync,
 {
-    type Item = (BGPStatusResult, BGPStatusResult);
+    type Item = BGPTableResult;
 
     async fn run(&self) -> anyhow::Result<Self::Item> {
-        try_join!(self.ipv4(), self.ipv6())
+        let (tables, details) = match self.format().await {
+            BGPFormat::Json => join!(self.json(), self.details_json()),
+            _ => join!(self.text(), self.details_text()),
+        };
+        let mut tables = tables?;
+
+        // Details are supplementary, so failing to retrieve them must not discard the summaries.
+        let details = details.unwrap_or_else(|e| {
+            log::debug!("failed to retrieve BGP neighbor details\nError: {e:?}");
+            vec![]
+        });
+
+        // Details are per session, so attach them to the first address family of each neighbor in its VRF.
+        let mut details: HashMap<_, _> = details.into_iter().map(|(vrf, d)| ((vrf, d.neighbor), d)).collect();
+        for table in &mut tables {
+            for neighbor in &mut table.status.neighbors {
+                neighbor.detail = details.remove(&(table.vrf.clone(), neighbor.neighbor));
+            }
+        }
+        Ok(tables)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{net::{IpAddr, Ipv4Addr, Ipv6Addr}, time::Duration};
+    use std::{future::Future, net::{IpAddr, Ipv4Addr, Ipv6Addr}, time::Duration};
 
-    use futures::future::ok;
+    use chrono::NaiveDate;
+    use anyhow::anyhow;
+    use futures::future::{err, ok};
     use indoc::indoc;
     use mockall::{mock, predicate::eq};
     use pretty_assertions::assert_eq;
 
     use crate::{
-        domain::bgp::{BGPNeighbor, BGPStatus},
+        domain::{
+            bgp::{BGPMessageCounters, BGPNeighbor, BGPNeighborAddressFamily, BGPNeighborDetail, BGPStatus},
+            version::Version,
+        },
         infrastructure::cmd::runner::MockExecutor,
     };
 
@@ -85,6 +204,125 @@ mod tests {
         }
     }
 
+    mock! {
+        BGPNeighborDetailParser {}
+
+        impl Parser for BGPNeighborDetailParser {
+            type Context<'a> = ();
+            type Item = BGPNeighborDetailResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        BGPJsonParser {}
+
+        impl Parser for BGPJsonParser {
+            type Context<'a> = ();
+            type Item = BGPTableResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        BGPNeighborDetailJsonParser {}
+
+        impl Parser for BGPNeighborDetailJsonParser {
+            type Context<'a> = ();
+            type Item = BGPVrfNeighborDetailResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        VersionRunner {}
+
+        impl Runner for VersionRunner {
+            type Item = VersionResult;
+
+            fn run(&self) -> impl Future<Output = anyhow::Result<<Self as Runner>::Item>> + Send;
+        }
+    }
+
+    fn mock_version_runner(version: &'static str) -> MockVersionRunner {
+        let mut mock_version_runner = MockVersionRunner::new();
+        mock_version_runner
+            .expect_run()
+            .times(1)
+            .returning(move || Box::pin(ok(Version {
+                version: version.to_string(),
+                build_id: "5208541".to_string(),
+                build_on: NaiveDate::from_ymd_opt(2006, 1, 2).and_then(|d| d.and_hms_opt(15, 4, 0)).unwrap(),
+                copyright: "2012-2018 Ubiquiti Networks, Inc.".to_string(),
+                hw_model: "EdgeRouter X 5-Port".to_string(),
+                hw_serial_number: "000000000000".to_string(),
+                uptime: "01:00:00 up  1:00,  1 user,  load average: 1.00, 1.00, 1.00".to_string(),
+            })));
+        mock_version_runner
+    }
+
+    fn bgp_table(vrf: &str, afi: &str, safi: &str, neighbor: IpAddr) -> BGPTable {
+        BGPTable {
+            vrf: vrf.to_string(),
+            afi: afi.to_string(),
+            safi: safi.to_string(),
+            status: BGPStatus {
+                router_id: "192.0.2.1".to_string(),
+                local_as: 64496,
+                table_version: 128,
+                as_paths: None,
+                communities: None,
+                ebgp_maximum_paths: None,
+                ibgp_maximum_paths: None,
+                neighbors: vec![
+                    BGPNeighbor {
+                        neighbor,
+                        version: 4,
+                        remote_as: 64497,
+                        messages_received: 1000,
+                        messages_sent: 5000,
+                        table_version: 0,
+                        in_queue: 1,
+                        out_queue: 5,
+                        uptime: Some(Duration::new(4271, 0)),
+                        state: None,
+                        prefixes_received: Some(9),
+                        detail: None,
+                    },
+                ],
+                sessions: 1,
+            },
+        }
+    }
+
+    fn bgp_neighbor_detail(neighbor: IpAddr, description: &str) -> BGPNeighborDetail {
+        BGPNeighborDetail {
+            neighbor,
+            description: Some(description.to_string()),
+            connections_established: Some(1),
+            connections_dropped: Some(0),
+            last_reset_reason: None,
+            hold_time: Some(Duration::new(180, 0)),
+            keepalive_interval: Some(Duration::new(60, 0)),
+            messages_sent: BGPMessageCounters::default(),
+            messages_received: BGPMessageCounters::default(),
+            address_families: vec![],
+        }
+    }
+
+    fn mock_detail_parser() -> MockBGPNeighborDetailParser {
+        let mut mock_detail_parser = MockBGPNeighborDetailParser::new();
+        mock_detail_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(""), eq(()))
+            .returning(|_, _| Ok(vec![]));
+        mock_detail_parser
+    }
+
     #[tokio::test]
     async fn none() {
         let command = VtyshCommand::from("/opt/vyatta/sbin/ubnt_vtysh".to_string());
@@ -100,6 +338,11 @@ mod tests {
             .times(1)
             .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show bgp ipv6 summary"]))
             .returning(|_, _| Box::pin(ok("".to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp neighbors"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
 
         let mut mock_parser = MockBGPParser::new();
         mock_parser
@@ -108,9 +351,9 @@ mod tests {
             .with(eq(""), eq(()))
             .returning(|_, _| Ok(None));
 
-        let runner = BGPRunner::new(command, mock_executor, mock_parser);
+        let runner = BGPRunner::new(command, BGPFormat::Text, mock_executor, mock_parser, mock_detail_parser(), MockBGPJsonParser::new(), MockBGPNeighborDetailJsonParser::new(), MockVersionRunner::new());
         let actual = runner.run().await.unwrap();
-        assert_eq!(actual, (None, None));
+        assert_eq!(actual, vec![]);
     }
 
     #[tokio::test]
@@ -143,6 +386,11 @@ mod tests {
             .times(1)
             .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show bgp ipv6 summary"]))
             .returning(|_, _| Box::pin(ok("".to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp neighbors"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
 
         let mut mock_parser = MockBGPParser::new();
         mock_parser
@@ -153,8 +401,8 @@ mod tests {
                 router_id: "192.0.2.1".to_string(),
                 local_as: 64496,
                 table_version: 128,
-                as_paths: 1,
-                communities: 2,
+                as_paths: Some(1),
+                communities: Some(2),
                 ebgp_maximum_paths: Some(8),
                 ibgp_maximum_paths: Some(4),
                 neighbors: vec![
@@ -170,6 +418,7 @@ mod tests {
                         uptime: Some(Duration::new(4271, 0)),
                         state: None,
                         prefixes_received: Some(9),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
@@ -183,6 +432,7 @@ mod tests {
                         uptime: Some(Duration::new(93780, 0)),
                         state: None,
                         prefixes_received: Some(10),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
@@ -196,6 +446,7 @@ mod tests {
                         uptime: None,
                         state: Some("Connect".to_string()),
                         prefixes_received: None,
+                        detail: None,
                     },
                 ],
                 sessions: 2,
@@ -206,62 +457,69 @@ mod tests {
             .with(eq(""), eq(()))
             .returning(|_, _| Ok(None));
 
-        let runner = BGPRunner::new(command, mock_executor, mock_parser);
+        let runner = BGPRunner::new(command, BGPFormat::Text, mock_executor, mock_parser, mock_detail_parser(), MockBGPJsonParser::new(), MockBGPNeighborDetailJsonParser::new(), MockVersionRunner::new());
         let actual = runner.run().await.unwrap();
-        assert_eq!(actual, (
-            Some(BGPStatus {
-                router_id: "192.0.2.1".to_string(),
-                local_as: 64496,
-                table_version: 128,
-                as_paths: 1,
-                communities: 2,
-                ebgp_maximum_paths: Some(8),
-                ibgp_maximum_paths: Some(4),
-                neighbors: vec![
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 1000,
-                        messages_sent: 5000,
-                        table_version: 128,
-                        in_queue: 1,
-                        out_queue: 5,
-                        uptime: Some(Duration::new(4271, 0)),
-                        state: None,
-                        prefixes_received: Some(9),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 2000,
-                        messages_sent: 6000,
-                        table_version: 128,
-                        in_queue: 2,
-                        out_queue: 6,
-                        uptime: Some(Duration::new(93780, 0)),
-                        state: None,
-                        prefixes_received: Some(10),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 0,
-                        messages_sent: 0,
-                        table_version: 0,
-                        in_queue: 0,
-                        out_queue: 0,
-                        uptime: None,
-                        state: Some("Connect".to_string()),
-                        prefixes_received: None,
-                    },
-                ],
-                sessions: 2,
-            }),
-            None,
-        ));
+        assert_eq!(actual, vec![
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "ipv4".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 128,
+                    as_paths: Some(1),
+                    communities: Some(2),
+                    ebgp_maximum_paths: Some(8),
+                    ibgp_maximum_paths: Some(4),
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 1000,
+                            messages_sent: 5000,
+                            table_version: 128,
+                            in_queue: 1,
+                            out_queue: 5,
+                            uptime: Some(Duration::new(4271, 0)),
+                            state: None,
+                            prefixes_received: Some(9),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 2000,
+                            messages_sent: 6000,
+                            table_version: 128,
+                            in_queue: 2,
+                            out_queue: 6,
+                            uptime: Some(Duration::new(93780, 0)),
+                            state: None,
+                            prefixes_received: Some(10),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 0,
+                            messages_sent: 0,
+                            table_version: 0,
+                            in_queue: 0,
+                            out_queue: 0,
+                            uptime: None,
+                            state: Some("Connect".to_string()),
+                            prefixes_received: None,
+                            detail: None,
+                        },
+                    ],
+                    sessions: 2,
+                },
+            },
+        ]);
     }
 
     #[tokio::test]
@@ -295,6 +553,11 @@ mod tests {
             .times(1)
             .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show bgp ipv6 summary"]))
             .returning(|_, _| Box::pin(ok(ipv6_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp neighbors"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
 
         let mut mock_parser = MockBGPParser::new();
         mock_parser
@@ -310,8 +573,8 @@ mod tests {
                 router_id: "192.0.2.1".to_string(),
                 local_as: 64496,
                 table_version: 128,
-                as_paths: 1,
-                communities: 2,
+                as_paths: Some(1),
+                communities: Some(2),
                 ebgp_maximum_paths: Some(8),
                 ibgp_maximum_paths: Some(4),
                 neighbors: vec![
@@ -327,6 +590,7 @@ mod tests {
                         uptime: Some(Duration::new(12813, 0)),
                         state: None,
                         prefixes_received: Some(11),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
@@ -340,6 +604,7 @@ mod tests {
                         uptime: Some(Duration::new(363960, 0)),
                         state: None,
                         prefixes_received: Some(12),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
@@ -353,67 +618,75 @@ mod tests {
                         uptime: None,
                         state: Some("Connect".to_string()),
                         prefixes_received: None,
+                        detail: None,
                     },
                 ],
                 sessions: 2,
             })));
 
-        let runner = BGPRunner::new(command, mock_executor, mock_parser);
+        let runner = BGPRunner::new(command, BGPFormat::Text, mock_executor, mock_parser, mock_detail_parser(), MockBGPJsonParser::new(), MockBGPNeighborDetailJsonParser::new(), MockVersionRunner::new());
         let actual = runner.run().await.unwrap();
-        assert_eq!(actual, (
-            None,
-            Some(BGPStatus {
-                router_id: "192.0.2.1".to_string(),
-                local_as: 64496,
-                table_version: 128,
-                as_paths: 1,
-                communities: 2,
-                ebgp_maximum_paths: Some(8),
-                ibgp_maximum_paths: Some(4),
-                neighbors: vec![
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 3000,
-                        messages_sent: 7000,
-                        table_version: 128,
-                        in_queue: 3,
-                        out_queue: 7,
-                        uptime: Some(Duration::new(12813, 0)),
-                        state: None,
-                        prefixes_received: Some(11),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 4000,
-                        messages_sent: 8000,
-                        table_version: 128,
-                        in_queue: 4,
-                        out_queue: 8,
-                        uptime: Some(Duration::new(363960, 0)),
-                        state: None,
-                        prefixes_received: Some(12),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 0,
-                        messages_sent: 0,
-                        table_version: 0,
-                        in_queue: 0,
-                        out_queue: 0,
-                        uptime: None,
-                        state: Some("Connect".to_string()),
-                        prefixes_received: None,
-                    },
-                ],
-                sessions: 2,
-            }),
-        ));
+        assert_eq!(actual, vec![
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "ipv6".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 128,
+                    as_paths: Some(1),
+                    communities: Some(2),
+                    ebgp_maximum_paths: Some(8),
+                    ibgp_maximum_paths: Some(4),
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 3000,
+                            messages_sent: 7000,
+                            table_version: 128,
+                            in_queue: 3,
+                            out_queue: 7,
+                            uptime: Some(Duration::new(12813, 0)),
+                            state: None,
+                            prefixes_received: Some(11),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 4000,
+                            messages_sent: 8000,
+                            table_version: 128,
+                            in_queue: 4,
+                            out_queue: 8,
+                            uptime: Some(Duration::new(363960, 0)),
+                            state: None,
+                            prefixes_received: Some(12),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 0,
+                            messages_sent: 0,
+                            table_version: 0,
+                            in_queue: 0,
+                            out_queue: 0,
+                            uptime: None,
+                            state: Some("Connect".to_string()),
+                            prefixes_received: None,
+                            detail: None,
+                        },
+                    ],
+                    sessions: 2,
+                },
+            },
+        ]);
     }
 
     #[tokio::test]
@@ -464,6 +737,11 @@ mod tests {
             .times(1)
             .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show bgp ipv6 summary"]))
             .returning(|_, _| Box::pin(ok(ipv6_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp neighbors"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
 
         let mut mock_parser = MockBGPParser::new();
         mock_parser
@@ -474,8 +752,8 @@ mod tests {
                 router_id: "192.0.2.1".to_string(),
                 local_as: 64496,
                 table_version: 128,
-                as_paths: 1,
-                communities: 2,
+                as_paths: Some(1),
+                communities: Some(2),
                 ebgp_maximum_paths: Some(8),
                 ibgp_maximum_paths: Some(4),
                 neighbors: vec![
@@ -491,6 +769,7 @@ mod tests {
                         uptime: Some(Duration::new(4271, 0)),
                         state: None,
                         prefixes_received: Some(9),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
@@ -504,6 +783,7 @@ mod tests {
                         uptime: Some(Duration::new(93780, 0)),
                         state: None,
                         prefixes_received: Some(10),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
@@ -517,6 +797,7 @@ mod tests {
                         uptime: None,
                         state: Some("Connect".to_string()),
                         prefixes_received: None,
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
@@ -530,6 +811,7 @@ mod tests {
                         uptime: Some(Duration::new(12813, 0)),
                         state: None,
                         prefixes_received: Some(0),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
@@ -543,6 +825,7 @@ mod tests {
                         uptime: Some(Duration::new(363960, 0)),
                         state: None,
                         prefixes_received: Some(0),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
@@ -556,6 +839,7 @@ mod tests {
                         uptime: None,
                         state: Some("Connect".to_string()),
                         prefixes_received: None,
+                        detail: None,
                     },
                 ],
                 sessions: 4,
@@ -568,8 +852,8 @@ mod tests {
                 router_id: "192.0.2.1".to_string(),
                 local_as: 64496,
                 table_version: 128,
-                as_paths: 1,
-                communities: 2,
+                as_paths: Some(1),
+                communities: Some(2),
                 ebgp_maximum_paths: Some(8),
                 ibgp_maximum_paths: Some(4),
                 neighbors: vec![
@@ -585,6 +869,7 @@ mod tests {
                         uptime: Some(Duration::new(12813, 0)),
                         state: None,
                         prefixes_received: Some(11),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
@@ -598,6 +883,7 @@ mod tests {
                         uptime: Some(Duration::new(363960, 0)),
                         state: None,
                         prefixes_received: Some(12),
+                        detail: None,
                     },
                     BGPNeighbor {
                         neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
@@ -611,22 +897,239 @@ mod tests {
                         uptime: None,
                         state: Some("Connect".to_string()),
                         prefixes_received: None,
+                        detail: None,
                     },
                 ],
                 sessions: 2,
             })));
 
-        let runner = BGPRunner::new(command, mock_executor, mock_parser);
+        let runner = BGPRunner::new(command, BGPFormat::Text, mock_executor, mock_parser, mock_detail_parser(), MockBGPJsonParser::new(), MockBGPNeighborDetailJsonParser::new(), MockVersionRunner::new());
         let actual = runner.run().await.unwrap();
-        assert_eq!(actual, (
-            Some(BGPStatus {
+        assert_eq!(actual, vec![
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "ipv4".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 128,
+                    as_paths: Some(1),
+                    communities: Some(2),
+                    ebgp_maximum_paths: Some(8),
+                    ibgp_maximum_paths: Some(4),
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 1000,
+                            messages_sent: 5000,
+                            table_version: 128,
+                            in_queue: 1,
+                            out_queue: 5,
+                            uptime: Some(Duration::new(4271, 0)),
+                            state: None,
+                            prefixes_received: Some(9),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 2000,
+                            messages_sent: 6000,
+                            table_version: 128,
+                            in_queue: 2,
+                            out_queue: 6,
+                            uptime: Some(Duration::new(93780, 0)),
+                            state: None,
+                            prefixes_received: Some(10),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 0,
+                            messages_sent: 0,
+                            table_version: 0,
+                            in_queue: 0,
+                            out_queue: 0,
+                            uptime: None,
+                            state: Some("Connect".to_string()),
+                            prefixes_received: None,
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 3000,
+                            messages_sent: 7000,
+                            table_version: 128,
+                            in_queue: 3,
+                            out_queue: 7,
+                            uptime: Some(Duration::new(12813, 0)),
+                            state: None,
+                            prefixes_received: Some(0),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 4000,
+                            messages_sent: 8000,
+                            table_version: 128,
+                            in_queue: 4,
+                            out_queue: 8,
+                            uptime: Some(Duration::new(363960, 0)),
+                            state: None,
+                            prefixes_received: Some(0),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 0,
+                            messages_sent: 0,
+                            table_version: 0,
+                            in_queue: 0,
+                            out_queue: 0,
+                            uptime: None,
+                            state: Some("Connect".to_string()),
+                            prefixes_received: None,
+                            detail: None,
+                        },
+                    ],
+                    sessions: 4,
+                },
+            },
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "ipv6".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 128,
+                    as_paths: Some(1),
+                    communities: Some(2),
+                    ebgp_maximum_paths: Some(8),
+                    ibgp_maximum_paths: Some(4),
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 3000,
+                            messages_sent: 7000,
+                            table_version: 128,
+                            in_queue: 3,
+                            out_queue: 7,
+                            uptime: Some(Duration::new(12813, 0)),
+                            state: None,
+                            prefixes_received: Some(11),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 4000,
+                            messages_sent: 8000,
+                            table_version: 128,
+                            in_queue: 4,
+                            out_queue: 8,
+                            uptime: Some(Duration::new(363960, 0)),
+                            state: None,
+                            prefixes_received: Some(12),
+                            detail: None,
+                        },
+                        BGPNeighbor {
+                            neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 0,
+                            messages_sent: 0,
+                            table_version: 0,
+                            in_queue: 0,
+                            out_queue: 0,
+                            uptime: None,
+                            state: Some("Connect".to_string()),
+                            prefixes_received: None,
+                            detail: None,
+                        },
+                    ],
+                    sessions: 2,
+                },
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn details() {
+        let command = VtyshCommand::from("/opt/vyatta/sbin/ubnt_vtysh".to_string());
+        let ipv4_output = indoc! {"
+            BGP router identifier 192.0.2.1, local AS number 64496
+            BGP table version is 128
+            1 BGP AS-PATH entries
+            2 BGP community entries
+
+            Neighbor                 V   AS   MsgRcv    MsgSen TblVer   InQ   OutQ    Up/Down   State/PfxRcd
+            192.0.2.2                4 64497 1000       5000     128      1      5  01:11:11               9
+
+            Total number of neighbors 1
+
+            Total number of Established sessions 1
+        "};
+        let detail_output = indoc! {"
+            BGP neighbor is 192.0.2.2, remote AS 64497, local AS 64496, external link
+             Description: upstream-a
+              Last read 00:00:11, hold time is 90, keepalive interval is 30 seconds
+              Received 1000 messages, 0 notifications, 0 in queue
+              Sent 5000 messages, 1 notifications, 0 in queue
+             For address family: IPv4 Unicast
+              9 accepted prefixes
+              4 announced prefixes
+
+             Connections established 3; dropped 2
+             Last reset 01:11:12, due to BGP Notification received
+            BGP neighbor is 192.0.2.5, remote AS 64498, local AS 64496, external link
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp summary"]))
+            .returning(|_, _| Box::pin(ok(ipv4_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show bgp ipv6 summary"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp neighbors"]))
+            .returning(|_, _| Box::pin(ok(detail_output.to_string())));
+
+        let mut mock_parser = MockBGPParser::new();
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(ipv4_output), eq(()))
+            .returning(|_, _| Ok(Some(BGPStatus {
                 router_id: "192.0.2.1".to_string(),
                 local_as: 64496,
                 table_version: 128,
-                as_paths: 1,
-                communities: 2,
-                ebgp_maximum_paths: Some(8),
-                ibgp_maximum_paths: Some(4),
+                as_paths: Some(1),
+                communities: Some(2),
+                ebgp_maximum_paths: None,
+                ibgp_maximum_paths: None,
                 neighbors: vec![
                     BGPNeighbor {
                         neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
@@ -640,87 +1143,276 @@ mod tests {
                         uptime: Some(Duration::new(4271, 0)),
                         state: None,
                         prefixes_received: Some(9),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 2000,
-                        messages_sent: 6000,
-                        table_version: 128,
-                        in_queue: 2,
-                        out_queue: 6,
-                        uptime: Some(Duration::new(93780, 0)),
-                        state: None,
-                        prefixes_received: Some(10),
-                    },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 4)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 0,
-                        messages_sent: 0,
-                        table_version: 0,
-                        in_queue: 0,
-                        out_queue: 0,
-                        uptime: None,
-                        state: Some("Connect".to_string()),
-                        prefixes_received: None,
+                        detail: None,
                     },
                 ],
-                sessions: 4,
-            }),
-            Some(BGPStatus {
-                router_id: "192.0.2.1".to_string(),
-                local_as: 64496,
-                table_version: 128,
-                as_paths: 1,
-                communities: 2,
-                ebgp_maximum_paths: Some(8),
-                ibgp_maximum_paths: Some(4),
-                neighbors: vec![
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 3000,
-                        messages_sent: 7000,
-                        table_version: 128,
-                        in_queue: 3,
-                        out_queue: 7,
-                        uptime: Some(Duration::new(12813, 0)),
-                        state: None,
-                        prefixes_received: Some(11),
+                sessions: 1,
+            })));
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(""), eq(()))
+            .returning(|_, _| Ok(None));
+
+        let mut mock_detail_parser = MockBGPNeighborDetailParser::new();
+        mock_detail_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(detail_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                BGPNeighborDetail {
+                    neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                    description: Some("upstream-a".to_string()),
+                    connections_established: Some(3),
+                    connections_dropped: Some(2),
+                    last_reset_reason: Some("BGP Notification received".to_string()),
+                    hold_time: Some(Duration::new(90, 0)),
+                    keepalive_interval: Some(Duration::new(30, 0)),
+                    messages_sent: BGPMessageCounters {
+                        notification: Some(1),
+                        ..Default::default()
                     },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x3)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 4000,
-                        messages_sent: 8000,
-                        table_version: 128,
-                        in_queue: 4,
-                        out_queue: 8,
-                        uptime: Some(Duration::new(363960, 0)),
-                        state: None,
-                        prefixes_received: Some(12),
+                    messages_received: BGPMessageCounters {
+                        notification: Some(0),
+                        ..Default::default()
                     },
-                    BGPNeighbor {
-                        neighbor: IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0xffff, 0x0ffff, 0xffff, 0xffff)),
-                        version: 4,
-                        remote_as: 64497,
-                        messages_received: 0,
-                        messages_sent: 0,
-                        table_version: 0,
-                        in_queue: 0,
-                        out_queue: 0,
-                        uptime: None,
-                        state: Some("Connect".to_string()),
-                        prefixes_received: None,
-                    },
-                ],
-                sessions: 2,
-            }),
-        ));
+                    address_families: vec![
+                        BGPNeighborAddressFamily {
+                            address_family: "IPv4 Unicast".to_string(),
+                            prefixes_accepted: Some(9),
+                            prefixes_advertised: Some(4),
+                        },
+                    ],
+                },
+                BGPNeighborDetail {
+                    neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 5)),
+                    description: None,
+                    connections_established: None,
+                    connections_dropped: None,
+                    last_reset_reason: None,
+                    hold_time: None,
+                    keepalive_interval: None,
+                    messages_sent: BGPMessageCounters::default(),
+                    messages_received: BGPMessageCounters::default(),
+                    address_families: vec![],
+                },
+            ]));
+
+        let runner = BGPRunner::new(command, BGPFormat::Text, mock_executor, mock_parser, mock_detail_parser, MockBGPJsonParser::new(), MockBGPNeighborDetailJsonParser::new(), MockVersionRunner::new());
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, vec![
+            BGPTable {
+                vrf: "default".to_string(),
+                afi: "ipv4".to_string(),
+                safi: "unicast".to_string(),
+                status: BGPStatus {
+                    router_id: "192.0.2.1".to_string(),
+                    local_as: 64496,
+                    table_version: 128,
+                    as_paths: Some(1),
+                    communities: Some(2),
+                    ebgp_maximum_paths: None,
+                    ibgp_maximum_paths: None,
+                    neighbors: vec![
+                        BGPNeighbor {
+                            neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                            version: 4,
+                            remote_as: 64497,
+                            messages_received: 1000,
+                            messages_sent: 5000,
+                            table_version: 128,
+                            in_queue: 1,
+                            out_queue: 5,
+                            uptime: Some(Duration::new(4271, 0)),
+                            state: None,
+                            prefixes_received: Some(9),
+                            detail: Some(BGPNeighborDetail {
+                                neighbor: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)),
+                                description: Some("upstream-a".to_string()),
+                                connections_established: Some(3),
+                                connections_dropped: Some(2),
+                                last_reset_reason: Some("BGP Notification received".to_string()),
+                                hold_time: Some(Duration::new(90, 0)),
+                                keepalive_interval: Some(Duration::new(30, 0)),
+                                messages_sent: BGPMessageCounters {
+                                    notification: Some(1),
+                                    ..Default::default()
+                                },
+                                messages_received: BGPMessageCounters {
+                                    notification: Some(0),
+                                    ..Default::default()
+                                },
+                                address_families: vec![
+                                    BGPNeighborAddressFamily {
+                                        address_family: "IPv4 Unicast".to_string(),
+                                        prefixes_accepted: Some(9),
+                                        prefixes_advertised: Some(4),
+                                    },
+                                ],
+                            }),
+                        },
+                    ],
+                    sessions: 1,
+                },
+            },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn json() {
+        let command = VtyshCommand::from("/usr/bin/vtysh".to_string());
+        let json_output = "{}";
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/bin/vtysh", &["-c", "show bgp vrf all summary json"]))
+            .returning(|_, _| Box::pin(ok(json_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/bin/vtysh", &["-c", "show bgp vrf all neighbors json"]))
+            .returning(|_, _| Box::pin(ok("{}".to_string())));
+
+        let mut mock_json_parser = MockBGPJsonParser::new();
+        mock_json_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(json_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                bgp_table("RED", "ipv4", "unicast", IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2))),
+                bgp_table("default", "ipv4", "unicast", IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2))),
+                bgp_table("default", "ipv6", "unicast", IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2))),
+                bgp_table("default", "l2vpn", "evpn", IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3))),
+            ]));
+
+        let mut mock_detail_json_parser = MockBGPNeighborDetailJsonParser::new();
+        mock_detail_json_parser
+            .expect_parse()
+            .times(1)
+            .with(eq("{}"), eq(()))
+            .returning(|_, _| Ok(vec![
+                ("BLUE".to_string(), bgp_neighbor_detail(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2)), "customer-b")),
+                ("RED".to_string(), bgp_neighbor_detail(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2)), "customer-a")),
+            ]));
+
+        let mut red = bgp_table("RED", "ipv4", "unicast", IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2)));
+        red.status.neighbors[0].detail = Some(bgp_neighbor_detail(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2)), "customer-a"));
+
+        let runner = BGPRunner::new(command, BGPFormat::Json, mock_executor, MockBGPParser::new(), MockBGPNeighborDetailParser::new(), mock_json_parser, mock_detail_json_parser, MockVersionRunner::new());
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, vec![
+            red,
+            bgp_table("default", "ipv4", "unicast", IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2))),
+            bgp_table("default", "ipv6", "unicast", IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x2))),
+            bgp_table("default", "l2vpn", "evpn", IpAddr::V4(Ipv4Addr::new(192, 0, 2, 3))),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn auto_json() {
+        let command = VtyshCommand::from("/usr/bin/vtysh".to_string());
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(2)
+            .withf(|command, args| (command, args) == ("/usr/bin/vtysh", &["-c", "show bgp vrf all summary json"]))
+            .returning(|_, _| Box::pin(ok("{}".to_string())));
+        mock_executor
+            .expect_output()
+            .times(2)
+            .withf(|command, args| (command, args) == ("/usr/bin/vtysh", &["-c", "show bgp vrf all neighbors json"]))
+            .returning(|_, _| Box::pin(ok("{}".to_string())));
+
+        let mut mock_detail_json_parser = MockBGPNeighborDetailJsonParser::new();
+        mock_detail_json_parser
+            .expect_parse()
+            .times(2)
+            .with(eq("{}"), eq(()))
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_json_parser = MockBGPJsonParser::new();
+        mock_json_parser
+            .expect_parse()
+            .times(2)
+            .with(eq("{}"), eq(()))
+            .returning(|_, _| Ok(vec![]));
+
+        let runner = BGPRunner::new(command, BGPFormat::Auto, mock_executor, MockBGPParser::new(), MockBGPNeighborDetailParser::new(), mock_json_parser, mock_detail_json_parser, mock_version_runner("v3.0.0"));
+        assert_eq!(runner.run().await.unwrap(), vec![]);
+        assert_eq!(runner.run().await.unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn auto_text() {
+        let command = VtyshCommand::from("/opt/vyatta/sbin/ubnt_vtysh".to_string());
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp summary"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show bgp ipv6 summary"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip bgp neighbors"]))
+            .returning(|_, _| Box::pin(ok("".to_string())));
+
+        let mut mock_parser = MockBGPParser::new();
+        mock_parser
+            .expect_parse()
+            .times(2)
+            .with(eq(""), eq(()))
+            .returning(|_, _| Ok(None));
+
+        let runner = BGPRunner::new(command, BGPFormat::Auto, mock_executor, mock_parser, mock_detail_parser(), MockBGPJsonParser::new(), MockBGPNeighborDetailJsonParser::new(), mock_version_runner("v2.0.9-hotfix.7"));
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[tokio::test]
+    async fn details_failure() {
+        let command = VtyshCommand::from("/usr/bin/vtysh".to_string());
+        let json_output = "{}";
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/bin/vtysh", &["-c", "show bgp vrf all summary json"]))
+            .returning(|_, _| Box::pin(ok(json_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/bin/vtysh", &["-c", "show bgp vrf all neighbors json"]))
+            .returning(|_, _| Box::pin(err(anyhow!("Process exited with 1"))));
+
+        let mut mock_detail_json_parser = MockBGPNeighborDetailJsonParser::new();
+        mock_detail_json_parser
+            .expect_parse()
+            .times(0);
+
+        let mut mock_json_parser = MockBGPJsonParser::new();
+        mock_json_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(json_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                bgp_table("default", "ipv4", "unicast", IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2))),
+            ]));
+
+        let runner = BGPRunner::new(command, BGPFormat::Json, mock_executor, MockBGPParser::new(), MockBGPNeighborDetailParser::new(), mock_json_parser, mock_detail_json_parser, MockVersionRunner::new());
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, vec![
+            bgp_table("default", "ipv4", "unicast", IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2))),
+        ]);
     }
 }
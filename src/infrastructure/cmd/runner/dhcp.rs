@@ -0,0 +1,217 @@
+use crate::{
+    infrastructure::{
+        cmd::{parser::Parser, runner::Executor},
+        config::env::OpCommand,
+    },
+    service::{
+        dhcp::{DhcpLeaseResult, DhcpStatisticsResult},
+        Runner,
+    },
+};
+
+pub struct DhcpRunner<E, StatisticsParser, LeaseParser> {
+    command: OpCommand,
+    leases: bool,
+    executor: E,
+    statistics_parser: StatisticsParser,
+    lease_parser: LeaseParser,
+}
+
+impl<E, StatisticsParser, LeaseParser> DhcpRunner<E, StatisticsParser, LeaseParser>
+where
+    E: Executor + Send + Sync,
+    StatisticsParser: Parser<Context<'static> = (), Item = DhcpStatisticsResult> + Send + Sync,
+    LeaseParser: Parser<Context<'static> = (), Item = DhcpLeaseResult> + Send + Sync,
+{
+    pub fn new(command: OpCommand, leases: bool, executor: E, statistics_parser: StatisticsParser, lease_parser: LeaseParser) -> Self {
+        Self {
+            command,
+            leases,
+            executor,
+            statistics_parser,
+            lease_parser,
+        }
+    }
+
+    async fn statistics(&self) -> anyhow::Result<DhcpStatisticsResult> {
+        let output = self.executor.output(&self.command, &["show", "dhcp", "statistics"]).await?;
+        let result = self.statistics_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn leases(&self) -> anyhow::Result<Option<DhcpLeaseResult>> {
+        if !self.leases {
+            return Ok(None);
+        }
+
+        let output = self.executor.output(&self.command, &["show", "dhcp", "leases"]).await?;
+        let result = self.lease_parser.parse(&output, ())?;
+        Ok(Some(result))
+    }
+}
+
+impl<E, StatisticsParser, LeaseParser> Runner for DhcpRunner<E, StatisticsParser, LeaseParser>
+where
+    E: Executor + Send + Sync,
+    StatisticsParser: Parser<Context<'static> = (), Item = DhcpStatisticsResult> + Send + Sync,
+    LeaseParser: Parser<Context<'static> = (), Item = DhcpLeaseResult> + Send + Sync,
+{
+    type Item = (DhcpStatisticsResult, Option<DhcpLeaseResult>);
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        let statistics = self.statistics().await?;
+        let leases = self.leases().await?;
+        Ok((statistics, leases))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use chrono::NaiveDate;
+    use futures::future::ok;
+    use indoc::indoc;
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::dhcp::{DhcpLease, DhcpPool},
+        infrastructure::cmd::runner::MockExecutor,
+    };
+
+    use super::*;
+
+    mock! {
+        DhcpStatisticsParser {}
+
+        impl Parser for DhcpStatisticsParser {
+            type Context<'a> = ();
+            type Item = DhcpStatisticsResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        DhcpLeaseParser {}
+
+        impl Parser for DhcpLeaseParser {
+            type Context<'a> = ();
+            type Item = DhcpLeaseResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    const STATISTICS_OUTPUT: &str = indoc! {"
+        pool                                    pool size   # leased    # avail
+        ----                                    ---------   --------    -------
+        LAN                                     201         1           200
+    "};
+
+    fn mock_executor() -> MockExecutor {
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "dhcp", "statistics"]))
+            .returning(|_, _| Box::pin(ok(STATISTICS_OUTPUT.to_string())));
+        mock_executor
+    }
+
+    fn mock_statistics_parser() -> MockDhcpStatisticsParser {
+        let mut mock_statistics_parser = MockDhcpStatisticsParser::new();
+        mock_statistics_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(STATISTICS_OUTPUT), eq(()))
+            .returning(|_, _| Ok(vec![
+                DhcpPool {
+                    pool: "LAN".to_string(),
+                    pool_size: 201,
+                    leased: 1,
+                    available: 200,
+                },
+            ]));
+        mock_statistics_parser
+    }
+
+    #[tokio::test]
+    async fn dhcp() {
+        let command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
+        let lease_output = indoc! {"
+            IP address      Hardware Address   Lease expiration     Pool       Client Name
+            ----------      ----------------   ----------------     ----       -----------
+            192.168.1.10    00:11:22:33:44:55  2006/01/02 15:04:05  LAN        laptop
+        "};
+
+        let mut mock_executor = mock_executor();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "dhcp", "leases"]))
+            .returning(|_, _| Box::pin(ok(lease_output.to_string())));
+
+        let mut mock_lease_parser = MockDhcpLeaseParser::new();
+        mock_lease_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(lease_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                DhcpLease {
+                    ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+                    mac_address: "00:11:22:33:44:55".to_string(),
+                    expiration: NaiveDate::from_ymd_opt(2006, 1, 2).and_then(|d| d.and_hms_opt(15, 4, 5)),
+                    pool: "LAN".to_string(),
+                    client_name: Some("laptop".to_string()),
+                },
+            ]));
+
+        let runner = DhcpRunner::new(command, true, mock_executor, mock_statistics_parser(), mock_lease_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, (
+            vec![
+                DhcpPool {
+                    pool: "LAN".to_string(),
+                    pool_size: 201,
+                    leased: 1,
+                    available: 200,
+                },
+            ],
+            Some(vec![
+                DhcpLease {
+                    ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+                    mac_address: "00:11:22:33:44:55".to_string(),
+                    expiration: NaiveDate::from_ymd_opt(2006, 1, 2).and_then(|d| d.and_hms_opt(15, 4, 5)),
+                    pool: "LAN".to_string(),
+                    client_name: Some("laptop".to_string()),
+                },
+            ]),
+        ));
+    }
+
+    #[tokio::test]
+    async fn dhcp_without_leases() {
+        let command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
+
+        let mut mock_lease_parser = MockDhcpLeaseParser::new();
+        mock_lease_parser
+            .expect_parse()
+            .times(0);
+
+        let runner = DhcpRunner::new(command, false, mock_executor(), mock_statistics_parser(), mock_lease_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, (
+            vec![
+                DhcpPool {
+                    pool: "LAN".to_string(),
+                    pool_size: 201,
+                    leased: 1,
+                    available: 200,
+                },
+            ],
+            None,
+        ));
+    }
+}
@@ -0,0 +1,196 @@
+use tokio::try_join;
+
+use crate::{
+    infrastructure::{
+        cmd::{parser::Parser, runner::Executor},
+        config::env::OpCommand,
+    },
+    service::{
+        firewall::{FirewallResult, NatResult},
+        Runner,
+    },
+};
+
+pub struct FirewallRunner<E, FirewallParser, NatParser> {
+    command: OpCommand,
+    executor: E,
+    firewall_parser: FirewallParser,
+    nat_parser: NatParser,
+}
+
+impl<E, FirewallParser, NatParser> FirewallRunner<E, FirewallParser, NatParser>
+where
+    E: Executor + Send + Sync,
+    FirewallParser: Parser<Context<'static> = (), Item = FirewallResult> + Send + Sync,
+    NatParser: Parser<Context<'static> = (), Item = NatResult> + Send + Sync,
+{
+    pub fn new(command: OpCommand, executor: E, firewall_parser: FirewallParser, nat_parser: NatParser) -> Self {
+        Self {
+            command,
+            executor,
+            firewall_parser,
+            nat_parser,
+        }
+    }
+
+    async fn firewall(&self) -> anyhow::Result<FirewallResult> {
+        let output = self.executor.output(&self.command, &["show", "firewall", "statistics"]).await?;
+        let result = self.firewall_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn nat(&self) -> anyhow::Result<NatResult> {
+        let output = self.executor.output(&self.command, &["show", "nat", "statistics"]).await?;
+        let result = self.nat_parser.parse(&output, ())?;
+        Ok(result)
+    }
+}
+
+impl<E, FirewallParser, NatParser> Runner for FirewallRunner<E, FirewallParser, NatParser>
+where
+    E: Executor + Send + Sync,
+    FirewallParser: Parser<Context<'static> = (), Item = FirewallResult> + Send + Sync,
+    NatParser: Parser<Context<'static> = (), Item = NatResult> + Send + Sync,
+{
+    type Item = (FirewallResult, NatResult);
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        try_join!(self.firewall(), self.nat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::ok;
+    use indoc::indoc;
+    use mockall::{mock, predicate::eq};
+    use number_prefix::NumberPrefix;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::firewall::{FirewallRule, FirewallRuleset, NatRule},
+        infrastructure::cmd::runner::MockExecutor,
+    };
+
+    use super::*;
+
+    mock! {
+        FirewallParser {}
+
+        impl Parser for FirewallParser {
+            type Context<'a> = ();
+            type Item = FirewallResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        NatParser {}
+
+        impl Parser for NatParser {
+            type Context<'a> = ();
+            type Item = NatResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    #[tokio::test]
+    async fn firewall() {
+        let command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
+        let firewall_output = indoc! {r#"
+            IPv4 Firewall "WAN_IN":
+
+            rule  packets   bytes     action  description
+            ----  -------   -----     ------  -----------
+            10000 26        300       DROP    DEFAULT ACTION
+        "#};
+        let nat_output = indoc! {"
+            rule   pkts        bytes   type  IN    OUT   description
+            ----   ----        -----   ----  --    ---   -----------
+            5000   23          191     MASQ  -     eth0  masquerade for WAN
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "firewall", "statistics"]))
+            .returning(|_, _| Box::pin(ok(firewall_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "nat", "statistics"]))
+            .returning(|_, _| Box::pin(ok(nat_output.to_string())));
+
+        let mut mock_firewall_parser = MockFirewallParser::new();
+        mock_firewall_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(firewall_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                FirewallRuleset {
+                    ip_version: "ipv4".to_string(),
+                    name: "WAN_IN".to_string(),
+                    rules: vec![
+                        FirewallRule {
+                            rule: 10000,
+                            packets: NumberPrefix::Standalone(26.0).into(),
+                            bytes: NumberPrefix::Standalone(300.0).into(),
+                            action: "DROP".to_string(),
+                            description: Some("DEFAULT ACTION".to_string()),
+                        },
+                    ],
+                },
+            ]));
+
+        let mut mock_nat_parser = MockNatParser::new();
+        mock_nat_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(nat_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                NatRule {
+                    rule: 5000,
+                    packets: NumberPrefix::Standalone(23.0).into(),
+                    bytes: NumberPrefix::Standalone(191.0).into(),
+                    nat_type: "MASQ".to_string(),
+                    inbound_interface: None,
+                    outbound_interface: Some("eth0".to_string()),
+                    description: Some("masquerade for WAN".to_string()),
+                },
+            ]));
+
+        let runner = FirewallRunner::new(command, mock_executor, mock_firewall_parser, mock_nat_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, (
+            vec![
+                FirewallRuleset {
+                    ip_version: "ipv4".to_string(),
+                    name: "WAN_IN".to_string(),
+                    rules: vec![
+                        FirewallRule {
+                            rule: 10000,
+                            packets: NumberPrefix::Standalone(26.0).into(),
+                            bytes: NumberPrefix::Standalone(300.0).into(),
+                            action: "DROP".to_string(),
+                            description: Some("DEFAULT ACTION".to_string()),
+                        },
+                    ],
+                },
+            ],
+            vec![
+                NatRule {
+                    rule: 5000,
+                    packets: NumberPrefix::Standalone(23.0).into(),
+                    bytes: NumberPrefix::Standalone(191.0).into(),
+                    nat_type: "MASQ".to_string(),
+                    inbound_interface: None,
+                    outbound_interface: Some("eth0".to_string()),
+                    description: Some("masquerade for WAN".to_string()),
+                },
+            ],
+        ));
+    }
+}
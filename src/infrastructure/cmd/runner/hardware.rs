@@ -0,0 +1,283 @@
+use tokio::try_join;
+
+use crate::{
+    infrastructure::{
+        cmd::{parser::Parser, runner::{Executor, ExitError}},
+        config::env::OpCommand,
+    },
+    service::{system::HardwareResult, Runner},
+};
+
+pub struct HardwareRunner<E, P> {
+    command: OpCommand,
+    executor: E,
+    parser: P,
+}
+
+impl<E, P> HardwareRunner<E, P>
+where
+    E: Executor + Send + Sync,
+    P: Parser<Context<'static> = (), Item = HardwareResult> + Send + Sync,
+{
+    pub fn new(command: OpCommand, executor: E, parser: P) -> Self {
+        Self {
+            command,
+            executor,
+            parser,
+        }
+    }
+
+    async fn temperature(&self) -> anyhow::Result<HardwareResult> {
+        let output = self.executor.output(&self.command, &["show", "hardware", "temperature"]).await?;
+        let result = self.parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn fan(&self) -> anyhow::Result<HardwareResult> {
+        let output = match self.executor.output(&self.command, &["show", "hardware", "fan"]).await {
+            Ok(output) => output,
+            // Models without a fan do not have the command at all.
+            Err(e) if e.downcast_ref::<ExitError>().is_some() => {
+                log::debug!("fan is not available: {e}");
+                return Ok(HardwareResult::default());
+            },
+            Err(e) => return Err(e),
+        };
+        let result = self.parser.parse(&output, ())?;
+        Ok(result)
+    }
+}
+
+impl<E, P> Runner for HardwareRunner<E, P>
+where
+    E: Executor + Send + Sync,
+    P: Parser<Context<'static> = (), Item = HardwareResult> + Send + Sync,
+{
+    type Item = HardwareResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        let (mut temperature, fan) = try_join!(self.temperature(), self.fan())?;
+
+        // Some models also report the fan speed along with the temperature.
+        if !fan.fans.is_empty() {
+            temperature.fans = fan.fans;
+        }
+        Ok(temperature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::{err, ok};
+    use indoc::indoc;
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::system::{Hardware, Sensor},
+        infrastructure::cmd::runner::MockExecutor,
+    };
+
+    use super::*;
+
+    mock! {
+        HardwareParser {}
+
+        impl Parser for HardwareParser {
+            type Context<'a> = ();
+            type Item = HardwareResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    #[tokio::test]
+    async fn hardware() {
+        let command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
+        let output = indoc! {"
+            CPU Temp:         56.0 C
+            Fan Speed:        3840 RPM
+        "};
+        let fan_output = indoc! {"
+            Fan is not available on this platform
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "hardware", "temperature"]))
+            .returning(|_, _| Box::pin(ok(output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "hardware", "fan"]))
+            .returning(|_, _| Box::pin(ok(fan_output.to_string())));
+
+        let mut mock_parser = MockHardwareParser::new();
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(output), eq(()))
+            .returning(|_, _| Ok(Hardware {
+                temperatures: vec![
+                    Sensor {
+                        name: "CPU Temp".to_string(),
+                        value: 56.0,
+                    },
+                ],
+                fans: vec![
+                    Sensor {
+                        name: "Fan Speed".to_string(),
+                        value: 3840.0,
+                    },
+                ],
+            }));
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(fan_output), eq(()))
+            .returning(|_, _| Ok(Hardware::default()));
+
+        let runner = HardwareRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, Hardware {
+            temperatures: vec![
+                Sensor {
+                    name: "CPU Temp".to_string(),
+                    value: 56.0,
+                },
+            ],
+            fans: vec![
+                Sensor {
+                    name: "Fan Speed".to_string(),
+                    value: 3840.0,
+                },
+            ],
+        });
+    }
+
+    #[tokio::test]
+    async fn fan() {
+        let command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
+        let output = indoc! {"
+            CPU Temp:         56.0 C
+        "};
+        let fan_output = indoc! {"
+            Fan1:             3840 RPM
+            Fan2:             3900 RPM
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "hardware", "temperature"]))
+            .returning(|_, _| Box::pin(ok(output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "hardware", "fan"]))
+            .returning(|_, _| Box::pin(ok(fan_output.to_string())));
+
+        let mut mock_parser = MockHardwareParser::new();
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(output), eq(()))
+            .returning(|_, _| Ok(Hardware {
+                temperatures: vec![
+                    Sensor {
+                        name: "CPU Temp".to_string(),
+                        value: 56.0,
+                    },
+                ],
+                fans: vec![],
+            }));
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(fan_output), eq(()))
+            .returning(|_, _| Ok(Hardware {
+                temperatures: vec![],
+                fans: vec![
+                    Sensor {
+                        name: "Fan1".to_string(),
+                        value: 3840.0,
+                    },
+                    Sensor {
+                        name: "Fan2".to_string(),
+                        value: 3900.0,
+                    },
+                ],
+            }));
+
+        let runner = HardwareRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, Hardware {
+            temperatures: vec![
+                Sensor {
+                    name: "CPU Temp".to_string(),
+                    value: 56.0,
+                },
+            ],
+            fans: vec![
+                Sensor {
+                    name: "Fan1".to_string(),
+                    value: 3840.0,
+                },
+                Sensor {
+                    name: "Fan2".to_string(),
+                    value: 3900.0,
+                },
+            ],
+        });
+    }
+
+    #[tokio::test]
+    async fn fan_not_supported() {
+        let command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
+        let output = indoc! {"
+            CPU Temp:         56.0 C
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "hardware", "temperature"]))
+            .returning(|_, _| Box::pin(ok(output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "hardware", "fan"]))
+            .returning(|_, _| Box::pin(err(ExitError::new(Some(1), "Invalid command: show hardware [fan]\n".to_string(), String::new()).into())));
+
+        let mut mock_parser = MockHardwareParser::new();
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(output), eq(()))
+            .returning(|_, _| Ok(Hardware {
+                temperatures: vec![
+                    Sensor {
+                        name: "CPU Temp".to_string(),
+                        value: 56.0,
+                    },
+                ],
+                fans: vec![],
+            }));
+
+        let runner = HardwareRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, Hardware {
+            temperatures: vec![
+                Sensor {
+                    name: "CPU Temp".to_string(),
+                    value: 56.0,
+                },
+            ],
+            fans: vec![],
+        });
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    infrastructure::{
+        cmd::{parser::Parser, runner::Executor},
+        config::env::IpCommand,
+    },
+    service::{interface::InterfaceResult, Runner},
+};
+
+pub struct InterfaceRunner<E, P> {
+    command: IpCommand,
+    executor: E,
+    parser: P,
+}
+
+impl<E, P> InterfaceRunner<E, P>
+where
+    E: Executor + Send + Sync,
+    P: Parser<Context<'static> = (), Item = InterfaceResult> + Send + Sync,
+{
+    pub fn new(command: IpCommand, executor: E, parser: P) -> Self {
+        Self {
+            command,
+            executor,
+            parser,
+        }
+    }
+
+    async fn interfaces(&self) -> anyhow::Result<InterfaceResult> {
+        let output = self.executor.output(&self.command, &["--brief", "addr", "show"]).await?;
+        let result = self.parser.parse(&output, ())?;
+        Ok(result)
+    }
+}
+
+impl<E, P> Runner for InterfaceRunner<E, P>
+where
+    E: Executor + Send + Sync,
+    P: Parser<Context<'static> = (), Item = InterfaceResult> + Send + Sync,
+{
+    type Item = InterfaceResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        self.interfaces().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use futures::future::ok;
+    use indoc::indoc;
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::interface::{AddrInfo, Interface},
+        infrastructure::cmd::runner::MockExecutor,
+    };
+
+    use super::*;
+
+    mock! {
+        InterfaceParser {}
+
+        impl Parser for InterfaceParser {
+            type Context<'a> = ();
+            type Item = InterfaceResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    #[tokio::test]
+    async fn interfaces() {
+        let command = IpCommand::from("/bin/ip".to_string());
+        let output = indoc! {r#"
+            lo               UNKNOWN        127.0.0.1/8 ::1/128
+            imq0             DOWN
+            pppoe0           UP             203.0.113.1 peer 192.0.2.255/32
+        "#};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/bin/ip", &["--brief", "addr", "show"]))
+            .returning(|_, _| Box::pin(ok(output.to_string())));
+
+        let mut mock_parser = MockInterfaceParser::new();
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(output), eq(()))
+            .returning(|_, _| Ok(vec![
+                Interface {
+                    ifname: "lo".to_string(),
+                    operstate: "UNKNOWN".to_string(),
+                    addr_info: vec![
+                        AddrInfo {
+                            local: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+                            address: None,
+                            prefixlen: 8,
+                        },
+                        AddrInfo {
+                            local: IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)),
+                            address: None,
+                            prefixlen: 128,
+                        },
+                    ],
+                },
+                Interface {
+                    ifname: "imq0".to_string(),
+                    operstate: "DOWN".to_string(),
+                    addr_info: vec![],
+                },
+                Interface {
+                    ifname: "pppoe0".to_string(),
+                    operstate: "UP".to_string(),
+                    addr_info: vec![
+                        AddrInfo {
+                            local: IpAddr::V4(Ipv4Addr::new(203, 0, 113, 1)),
+                            address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 255))),
+                            prefixlen: 32,
+                        },
+                    ],
+                },
+            ]));
+
+        let runner = InterfaceRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, vec![
+            Interface {
+                ifname: "lo".to_string(),
+                operstate: "UNKNOWN".to_string(),
+                addr_info: vec![
+                    AddrInfo {
+                        local: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+                        address: None,
+                        prefixlen: 8,
+                    },
+                    AddrInfo {
+                        local: IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)),
+                        address: None,
+                        prefixlen: 128,
+                    },
+                ],
+            },
+            Interface {
+                ifname: "imq0".to_string(),
+                operstate: "DOWN".to_string(),
+                addr_info: vec![],
+            },
+            Interface {
+                ifname: "pppoe0".to_string(),
+                operstate: "UP".to_string(),
+                addr_info: vec![
+                    AddrInfo {
+                        local: IpAddr::V4(Ipv4Addr::new(203, 0, 113, 1)),
+                        address: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 255))),
+                        prefixlen: 32,
+                    },
+                ],
+            },
+        ]);
+    }
+}
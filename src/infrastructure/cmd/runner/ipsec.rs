@@ -0,0 +1,203 @@
+use indexmap::IndexMap;
+
+use crate::{
+    infrastructure::{
+        cmd::{parser::Parser, runner::{ExitError, Executor}},
+        config::env::IPsecCommand,
+    },
+    service::{ipsec::IPsecResult, Runner},
+};
+
+pub struct IPsecRunner<E, P> {
+    command: IPsecCommand,
+    executor: E,
+    parser: P,
+}
+
+impl<E, P> IPsecRunner<E, P>
+where
+    E: Executor + Send + Sync,
+    P: Parser<Context<'static> = (), Item = IPsecResult> + Send + Sync,
+{
+    pub fn new(command: IPsecCommand, executor: E, parser: P) -> Self {
+        Self {
+            command,
+            executor,
+            parser,
+        }
+    }
+
+    async fn sas(&self) -> anyhow::Result<IPsecResult> {
+        let output = match self.executor.output(&self.command, &["statusall"]).await {
+            Ok(output) => output,
+            // ipsec exits with 3 without any output when charon is not running.
+            Err(e) if e.downcast_ref::<ExitError>().is_some_and(|e| e.code() == Some(3) && e.stdout().trim().is_empty()) => {
+                log::debug!("charon is not running: {e}");
+                return Ok(IndexMap::new());
+            },
+            Err(e) => return Err(e),
+        };
+        let result = self.parser.parse(&output, ())?;
+        Ok(result)
+    }
+}
+
+impl<E, P> Runner for IPsecRunner<E, P>
+where
+    E: Executor + Send + Sync,
+    P: Parser<Context<'static> = (), Item = IPsecResult> + Send + Sync,
+{
+    type Item = IPsecResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        self.sas().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use futures::future::{err, ok};
+    use indexmap::indexmap;
+    use indoc::indoc;
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::ipsec::{SA, SAState},
+        infrastructure::cmd::runner::MockExecutor,
+    };
+
+    use super::*;
+
+    mock! {
+        IPsecParser {}
+
+        impl Parser for IPsecParser {
+            type Context<'a> = ();
+            type Item = IPsecResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    #[tokio::test]
+    async fn sas() {
+        let command = IPsecCommand::from("/usr/sbin/ipsec".to_string());
+        let output = indoc! {"
+            Status of IKE charon daemon (strongSwan 5.7.2, Linux 4.9.79-UBNT, mips64):
+            Security Associations (0 up, 1 connecting):
+            peer-198.51.100.1-tunnel-1[1]: CONNECTING, 192.0.2.1[%any]...198.51.100.1[%any]
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/sbin/ipsec", &["statusall"]))
+            .returning(|_, _| Box::pin(ok(output.to_string())));
+
+        let mut mock_parser = MockIPsecParser::new();
+        mock_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(output), eq(()))
+            .returning(|_, _| Ok(indexmap! {
+                "peer-198.51.100.1-tunnel-1".to_string() => SA {
+                    uniqueid: 1,
+                    version: String::new(),
+                    state: SAState::Connecting,
+                    local_host: "192.0.2.1".to_string(),
+                    local_port: 0,
+                    local_id: "%any".to_string(),
+                    remote_host: "198.51.100.1".to_string(),
+                    remote_port: 0,
+                    remote_id: "%any".to_string(),
+                    remote_xauth_id: None,
+                    remote_eap_id: None,
+                    initiator: None,
+                    encr_alg: None,
+                    encr_keysize: None,
+                    integ_alg: None,
+                    integ_keysize: None,
+                    prf_alg: None,
+                    dh_group: None,
+                    established: None,
+                    rekey_time: None,
+                    reauth_time: None,
+                    child_sas: indexmap! {},
+                },
+            }));
+
+        let runner = IPsecRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, indexmap! {
+            "peer-198.51.100.1-tunnel-1".to_string() => SA {
+                uniqueid: 1,
+                version: String::new(),
+                state: SAState::Connecting,
+                local_host: "192.0.2.1".to_string(),
+                local_port: 0,
+                local_id: "%any".to_string(),
+                remote_host: "198.51.100.1".to_string(),
+                remote_port: 0,
+                remote_id: "%any".to_string(),
+                remote_xauth_id: None,
+                remote_eap_id: None,
+                initiator: None,
+                encr_alg: None,
+                encr_keysize: None,
+                integ_alg: None,
+                integ_keysize: None,
+                prf_alg: None,
+                dh_group: None,
+                established: None,
+                rekey_time: None,
+                reauth_time: None,
+                child_sas: indexmap! {},
+            },
+        });
+    }
+
+    #[tokio::test]
+    async fn not_running() {
+        let command = IPsecCommand::from("/usr/sbin/ipsec".to_string());
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/sbin/ipsec", &["statusall"]))
+            .returning(|_, _| Box::pin(err(ExitError::new(Some(3), String::new(), String::new()).into())));
+
+        let mut mock_parser = MockIPsecParser::new();
+        mock_parser
+            .expect_parse()
+            .times(0);
+
+        let runner = IPsecRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, indexmap! {});
+    }
+
+    #[tokio::test]
+    async fn failure() {
+        let command = IPsecCommand::from("/usr/sbin/ipsec".to_string());
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/usr/sbin/ipsec", &["statusall"]))
+            .returning(|_, _| Box::pin(err(anyhow!("No such file or directory (os error 2)"))));
+
+        let mut mock_parser = MockIPsecParser::new();
+        mock_parser
+            .expect_parse()
+            .times(0);
+
+        let runner = IPsecRunner::new(command, mock_executor, mock_parser);
+        let actual = runner.run().await;
+        assert!(actual.is_err());
+    }
+}
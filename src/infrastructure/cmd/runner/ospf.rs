@@ -0,0 +1,441 @@
+use tokio::try_join;
+
+use crate::{
+    infrastructure::{
+        cmd::{parser::Parser, runner::{ExitError, Executor}},
+        config::env::VtyshCommand,
+    },
+    service::{
+        ospf::{OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult},
+        Runner,
+    },
+};
+
+pub struct OSPFRunner<E, AreaParser, InterfaceParser, NeighborParser, Neighbor6Parser> {
+    command: VtyshCommand,
+    executor: E,
+    area_parser: AreaParser,
+    interface_parser: InterfaceParser,
+    neighbor_parser: NeighborParser,
+    neighbor6_parser: Neighbor6Parser,
+}
+
+impl<E, AreaParser, InterfaceParser, NeighborParser, Neighbor6Parser> OSPFRunner<E, AreaParser, InterfaceParser, NeighborParser, Neighbor6Parser>
+where
+    E: Executor + Send + Sync,
+    AreaParser: Parser<Context<'static> = (), Item = OSPFAreaResult> + Send + Sync,
+    InterfaceParser: Parser<Context<'static> = (), Item = OSPFInterfaceResult> + Send + Sync,
+    NeighborParser: Parser<Context<'static> = (), Item = OSPFNeighborResult> + Send + Sync,
+    Neighbor6Parser: Parser<Context<'static> = (), Item = OSPFNeighborResult> + Send + Sync,
+{
+    pub fn new(
+        command: VtyshCommand,
+        executor: E,
+        area_parser: AreaParser,
+        interface_parser: InterfaceParser,
+        neighbor_parser: NeighborParser,
+        neighbor6_parser: Neighbor6Parser,
+    ) -> Self {
+        Self {
+            command,
+            executor,
+            area_parser,
+            interface_parser,
+            neighbor_parser,
+            neighbor6_parser,
+        }
+    }
+
+    async fn areas(&self) -> anyhow::Result<OSPFAreaResult> {
+        let output = self.executor.output(&self.command, &["-c", "show ip ospf"]).await?;
+        let result = self.area_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn interfaces(&self) -> anyhow::Result<OSPFInterfaceResult> {
+        let output = self.executor.output(&self.command, &["-c", "show ip ospf interface"]).await?;
+        let result = self.interface_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn neighbors(&self) -> anyhow::Result<OSPFNeighborResult> {
+        let output = self.executor.output(&self.command, &["-c", "show ip ospf neighbor"]).await?;
+        let result = self.neighbor_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn neighbors6(&self) -> anyhow::Result<OSPFNeighborResult> {
+        let output = match self.executor.output(&self.command, &["-c", "show ipv6 ospf6 neighbor"]).await {
+            Ok(output) if is_ospf6d_not_running(&output) => {
+                log::debug!("ospf6d is not running");
+                return Ok(vec![]);
+            },
+            Ok(output) => output,
+            Err(e) if e.downcast_ref::<ExitError>().is_some_and(|e| is_ospf6d_not_running(e.stdout()) || is_ospf6d_not_running(e.stderr())) => {
+                log::debug!("ospf6d is not running: {e}");
+                return Ok(vec![]);
+            },
+            Err(e) => return Err(e),
+        };
+        let result = self.neighbor6_parser.parse(&output, ())?;
+        Ok(result)
+    }
+}
+
+impl<E, AreaParser, InterfaceParser, NeighborParser, Neighbor6Parser> Runner for OSPFRunner<E, AreaParser, InterfaceParser, NeighborParser, Neighbor6Parser>
+where
+    E: Executor + Send + Sync,
+    AreaParser: Parser<Context<'static> = (), Item = OSPFAreaResult> + Send + Sync,
+    InterfaceParser: Parser<Context<'static> = (), Item = OSPFInterfaceResult> + Send + Sync,
+    NeighborParser: Parser<Context<'static> = (), Item = OSPFNeighborResult> + Send + Sync,
+    Neighbor6Parser: Parser<Context<'static> = (), Item = OSPFNeighborResult> + Send + Sync,
+{
+    type Item = (OSPFAreaResult, OSPFInterfaceResult, OSPFNeighborResult, OSPFNeighborResult);
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        try_join!(self.areas(), self.interfaces(), self.neighbors(), self.neighbors6())
+    }
+}
+
+fn is_ospf6d_not_running(output: &str) -> bool {
+    output.contains("ospf6d is not running")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{IpAddr, Ipv4Addr},
+        time::Duration,
+    };
+
+    use futures::future::{err, ok};
+    use indoc::indoc;
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::ospf::{OSPFArea, OSPFInterface, OSPFNeighbor},
+        infrastructure::cmd::runner::{MockExecutor, TimeoutError},
+    };
+
+    use super::*;
+
+    mock! {
+        OSPFAreaParser {}
+
+        impl Parser for OSPFAreaParser {
+            type Context<'a> = ();
+            type Item = OSPFAreaResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        OSPFInterfaceParser {}
+
+        impl Parser for OSPFInterfaceParser {
+            type Context<'a> = ();
+            type Item = OSPFInterfaceResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        OSPFNeighborParser {}
+
+        impl Parser for OSPFNeighborParser {
+            type Context<'a> = ();
+            type Item = OSPFNeighborResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    #[tokio::test]
+    async fn ospf() {
+        let command = VtyshCommand::from("/opt/vyatta/sbin/ubnt_vtysh".to_string());
+        let area_output = indoc! {"
+             Number of areas attached to this router: 1
+                Area 0.0.0.0 (BACKBONE)
+                    SPF algorithm executed 5 times
+                    Number of LSA 6. Checksum 0x02e6c2
+        "};
+        let interface_output = indoc! {"
+            eth0 is up, line protocol is up
+              Internet Address 10.0.0.1/24, Area 0.0.0.0, MTU 1500
+              Process ID 0, Router ID 192.0.2.1, Network Type BROADCAST, Cost: 10
+              Transmit Delay is 1 sec, State DR, Priority 1, TE Metric 10
+        "};
+        let neighbor_output = indoc! {"
+            OSPF process 0:
+            Neighbor ID     Pri   State            Dead Time   Address         Interface           Instance ID
+            192.0.2.2         1   Full/Backup      00:00:37    10.0.0.2        eth0                    0
+        "};
+        let neighbor6_output = indoc! {"
+            Neighbor ID     Pri    DeadTime  State/IfState         Duration I/F[State]
+            192.0.2.2         1    00:00:35   Full/BDR             01:23:45 eth0[DR]
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf"]))
+            .returning(|_, _| Box::pin(ok(area_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf interface"]))
+            .returning(|_, _| Box::pin(ok(interface_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf neighbor"]))
+            .returning(|_, _| Box::pin(ok(neighbor_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ipv6 ospf6 neighbor"]))
+            .returning(|_, _| Box::pin(ok(neighbor6_output.to_string())));
+
+        let mut mock_area_parser = MockOSPFAreaParser::new();
+        mock_area_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(area_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                OSPFArea {
+                    area: "0.0.0.0".to_string(),
+                    lsas: 6,
+                    spf_runs: 5,
+                },
+            ]));
+
+        let mut mock_interface_parser = MockOSPFInterfaceParser::new();
+        mock_interface_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(interface_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                OSPFInterface {
+                    interface: "eth0".to_string(),
+                    area: "0.0.0.0".to_string(),
+                    cost: 10,
+                    state: Some("DR".to_string()),
+                },
+            ]));
+
+        let mut mock_neighbor_parser = MockOSPFNeighborParser::new();
+        mock_neighbor_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(neighbor_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                OSPFNeighbor {
+                    neighbor_id: "192.0.2.2".to_string(),
+                    priority: 1,
+                    state: "Full".to_string(),
+                    role: Some("Backup".to_string()),
+                    dead_time: Some(Duration::new(37, 0)),
+                    address: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2))),
+                    interface: "eth0".to_string(),
+                },
+            ]));
+
+        let mut mock_neighbor6_parser = MockOSPFNeighborParser::new();
+        mock_neighbor6_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(neighbor6_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                OSPFNeighbor {
+                    neighbor_id: "192.0.2.2".to_string(),
+                    priority: 1,
+                    state: "Full".to_string(),
+                    role: Some("BDR".to_string()),
+                    dead_time: Some(Duration::new(35, 0)),
+                    address: None,
+                    interface: "eth0".to_string(),
+                },
+            ]));
+
+        let runner = OSPFRunner::new(command, mock_executor, mock_area_parser, mock_interface_parser, mock_neighbor_parser, mock_neighbor6_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, (
+            vec![
+                OSPFArea {
+                    area: "0.0.0.0".to_string(),
+                    lsas: 6,
+                    spf_runs: 5,
+                },
+            ],
+            vec![
+                OSPFInterface {
+                    interface: "eth0".to_string(),
+                    area: "0.0.0.0".to_string(),
+                    cost: 10,
+                    state: Some("DR".to_string()),
+                },
+            ],
+            vec![
+                OSPFNeighbor {
+                    neighbor_id: "192.0.2.2".to_string(),
+                    priority: 1,
+                    state: "Full".to_string(),
+                    role: Some("Backup".to_string()),
+                    dead_time: Some(Duration::new(37, 0)),
+                    address: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2))),
+                    interface: "eth0".to_string(),
+                },
+            ],
+            vec![
+                OSPFNeighbor {
+                    neighbor_id: "192.0.2.2".to_string(),
+                    priority: 1,
+                    state: "Full".to_string(),
+                    role: Some("BDR".to_string()),
+                    dead_time: Some(Duration::new(35, 0)),
+                    address: None,
+                    interface: "eth0".to_string(),
+                },
+            ],
+        ));
+    }
+
+    #[tokio::test]
+    async fn ospf6_not_running() {
+        let command = VtyshCommand::from("/opt/vyatta/sbin/ubnt_vtysh".to_string());
+        let area_output = indoc! {"
+             Number of areas attached to this router: 1
+                Area 0.0.0.0 (BACKBONE)
+                    SPF algorithm executed 5 times
+                    Number of LSA 6. Checksum 0x02e6c2
+        "};
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf"]))
+            .returning(|_, _| Box::pin(ok(area_output.to_string())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf interface"]))
+            .returning(|_, _| Box::pin(ok(String::new())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf neighbor"]))
+            .returning(|_, _| Box::pin(ok(String::new())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ipv6 ospf6 neighbor"]))
+            .returning(|_, _| Box::pin(err(ExitError::new(Some(1), "% ospf6d is not running\n".to_string(), String::new()).into())));
+
+        let mut mock_area_parser = MockOSPFAreaParser::new();
+        mock_area_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(area_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                OSPFArea {
+                    area: "0.0.0.0".to_string(),
+                    lsas: 6,
+                    spf_runs: 5,
+                },
+            ]));
+
+        let mut mock_interface_parser = MockOSPFInterfaceParser::new();
+        mock_interface_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(""), eq(()))
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_neighbor_parser = MockOSPFNeighborParser::new();
+        mock_neighbor_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(""), eq(()))
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_neighbor6_parser = MockOSPFNeighborParser::new();
+        mock_neighbor6_parser
+            .expect_parse()
+            .times(0);
+
+        let runner = OSPFRunner::new(command, mock_executor, mock_area_parser, mock_interface_parser, mock_neighbor_parser, mock_neighbor6_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, (
+            vec![
+                OSPFArea {
+                    area: "0.0.0.0".to_string(),
+                    lsas: 6,
+                    spf_runs: 5,
+                },
+            ],
+            vec![],
+            vec![],
+            vec![],
+        ));
+    }
+
+    #[tokio::test]
+    async fn ospf6_timeout() {
+        let command = VtyshCommand::from("/opt/vyatta/sbin/ubnt_vtysh".to_string());
+
+        let mut mock_executor = MockExecutor::new();
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf"]))
+            .returning(|_, _| Box::pin(ok(String::new())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf interface"]))
+            .returning(|_, _| Box::pin(ok(String::new())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ip ospf neighbor"]))
+            .returning(|_, _| Box::pin(ok(String::new())));
+        mock_executor
+            .expect_output()
+            .times(1)
+            .withf(|command, args| (command, args) == ("/opt/vyatta/sbin/ubnt_vtysh", &["-c", "show ipv6 ospf6 neighbor"]))
+            .returning(|command, args| Box::pin(err(TimeoutError::new(
+                command.to_string(),
+                args.iter().map(|a| a.to_string()).collect(),
+                Duration::from_secs(5),
+            ).into())));
+
+        let mut mock_area_parser = MockOSPFAreaParser::new();
+        mock_area_parser
+            .expect_parse()
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_interface_parser = MockOSPFInterfaceParser::new();
+        mock_interface_parser
+            .expect_parse()
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_neighbor_parser = MockOSPFNeighborParser::new();
+        mock_neighbor_parser
+            .expect_parse()
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_neighbor6_parser = MockOSPFNeighborParser::new();
+        mock_neighbor6_parser
+            .expect_parse()
+            .times(0);
+
+        let runner = OSPFRunner::new(command, mock_executor, mock_area_parser, mock_interface_parser, mock_neighbor_parser, mock_neighbor6_parser);
+        let actual = runner.run().await.unwrap_err();
+        assert!(actual.is::<TimeoutError>());
+    }
+}
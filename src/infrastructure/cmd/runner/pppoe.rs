@@ -2,7 +2,7 @@ use crate::{
     domain::interface::Interface,
     infrastructure::{
         cmd::{parser::Parser, runner::Executor},
-        config::env::{IpCommand, OpCommand},
+        config::env::OpCommand,
     },
     service::{
         interface::InterfaceResult,
@@ -11,59 +11,45 @@ use crate::{
     },
 };
 
-pub struct PPPoERunner<E, PPPoEParser, InterfaceParser> {
-    op_command: OpCommand,
-    ip_command: IpCommand,
+pub struct PPPoERunner<E, P, I> {
+    command: OpCommand,
     executor: E,
-    pppoe_parser: PPPoEParser,
-    interface_parser: InterfaceParser,
+    parser: P,
+    interface_runner: I,
 }
 
-impl<E, PPPoEParser, InterfaceParser> PPPoERunner<E, PPPoEParser, InterfaceParser>
+impl<E, P, I> PPPoERunner<E, P, I>
 where
     E: Executor + Send + Sync,
-    PPPoEParser: for<'a> Parser<Context<'a> = (&'a [Interface],), Item = PPPoEClientSessionResult> + Send + Sync,
-    InterfaceParser: Parser<Context<'static> = (), Item = InterfaceResult> + Send + Sync,
+    P: for<'a> Parser<Context<'a> = (&'a [Interface],), Item = PPPoEClientSessionResult> + Send + Sync,
+    I: Runner<Item = InterfaceResult> + Send + Sync,
 {
-    pub fn new(
-        op_command: OpCommand,
-        ip_command: IpCommand,
-        executor: E,
-        pppoe_parser: PPPoEParser,
-        interface_parser: InterfaceParser,
-    ) -> Self {
+    pub fn new(command: OpCommand, executor: E, parser: P, interface_runner: I) -> Self {
         Self {
-            op_command,
-            ip_command,
+            command,
             executor,
-            pppoe_parser,
-            interface_parser,
+            parser,
+            interface_runner,
         }
     }
 
     async fn sessions(&self, interfaces: &[Interface]) -> anyhow::Result<PPPoEClientSessionResult> {
-        let output = self.executor.output(&self.op_command, &["show", "pppoe-client"]).await?;
-        let result = self.pppoe_parser.parse(&output, (interfaces,))?;
-        Ok(result)
-    }
-
-    async fn interfaces(&self) -> anyhow::Result<InterfaceResult> {
-        let output = self.executor.output(&self.ip_command, &["--brief", "addr", "show"]).await?;
-        let result = self.interface_parser.parse(&output, ())?;
+        let output = self.executor.output(&self.command, &["show", "pppoe-client"]).await?;
+        let result = self.parser.parse(&output, (interfaces,))?;
         Ok(result)
     }
 }
 
-impl<E, PPPoEParser, InterfaceParser> Runner for PPPoERunner<E, PPPoEParser, InterfaceParser>
+impl<E, P, I> Runner for PPPoERunner<E, P, I>
 where
     E: Executor + Send + Sync,
-    PPPoEParser: for<'a> Parser<Context<'a> = (&'a [Interface],), Item = PPPoEClientSessionResult> + Send + Sync,
-    InterfaceParser: Parser<Context<'static> = (), Item = InterfaceResult> + Send + Sync,
+    P: for<'a> Parser<Context<'a> = (&'a [Interface],), Item = PPPoEClientSessionResult> + Send + Sync,
+    I: Runner<Item = InterfaceResult> + Send + Sync,
 {
     type Item = PPPoEClientSessionResult;
 
     async fn run(&self) -> anyhow::Result<Self::Item> {
-        let interfaces = self.interfaces().await?;
+        let interfaces = self.interface_runner.run().await?;
         self.sessions(&interfaces).await
     }
 }
@@ -71,13 +57,14 @@ where
 #[cfg(test)]
 mod tests {
     use std::{
+        future::Future,
         net::{IpAddr, Ipv4Addr, Ipv6Addr},
         time::Duration,
     };
 
     use futures::future::ok;
     use indoc::indoc;
-    use mockall::{mock, predicate::eq};
+    use mockall::mock;
     use number_prefix::{NumberPrefix, Prefix};
     use pretty_assertions::assert_eq;
 
@@ -104,21 +91,18 @@ mod tests {
     }
 
     mock! {
-        InterfaceParser {}
+        InterfaceRunner {}
 
-        #[allow(clippy::extra_unused_lifetimes)]
-        impl Parser for InterfaceParser {
-            type Context<'a> = ();
+        impl Runner for InterfaceRunner {
             type Item = InterfaceResult;
 
-            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+            fn run(&self) -> impl Future<Output = anyhow::Result<<Self as Runner>::Item>> + Send;
         }
     }
 
     #[tokio::test]
     async fn sessions() {
         let op_command = OpCommand::from("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string());
-        let ip_command = IpCommand::from("/bin/ip".to_string());
         let pppoe_output = indoc! {"
             Active PPPoE client sessions:
 
@@ -129,12 +113,6 @@ mod tests {
 
             Total sessions: 2
         "};
-        let interface_output = indoc! {r#"
-            lo               UNKNOWN        127.0.0.1/8 ::1/128 
-            imq0             DOWN           
-            pppoe0           UP             203.0.113.1 peer 192.0.2.255/32 
-        "#};
-
         let interfaces = [
             Interface {
                 ifname: "lo".to_string(),
@@ -176,11 +154,6 @@ mod tests {
             .times(1)
             .withf(|command, args| (command, args) == ("/opt/vyatta/bin/vyatta-op-cmd-wrapper", &["show", "pppoe-client"]))
             .returning(|_, _| Box::pin(ok(pppoe_output.to_string())));
-        mock_executor
-            .expect_output()
-            .times(1)
-            .withf(|command, args| (command, args) == ("/bin/ip", &["--brief", "addr", "show"]))
-            .returning(|_, _| Box::pin(ok(interface_output.to_string())));
 
         let mut mock_pppoe_parser = MockPPPoEParser::new();
         mock_pppoe_parser
@@ -214,12 +187,11 @@ mod tests {
                 },
             ]));
 
-        let mut mock_interface_parser = MockInterfaceParser::new();
-        mock_interface_parser
-            .expect_parse()
+        let mut mock_interface_runner = MockInterfaceRunner::new();
+        mock_interface_runner
+            .expect_run()
             .times(1)
-            .with(eq(interface_output), eq(()))
-            .returning(|_, _| Ok(vec![
+            .returning(|| Box::pin(ok(vec![
                 Interface {
                     ifname: "lo".to_string(),
                     operstate: "UNKNOWN".to_string(),
@@ -252,9 +224,9 @@ mod tests {
                         },
                     ],
                 },
-            ]));
+            ])));
 
-        let runner = PPPoERunner::new(op_command, ip_command, mock_executor, mock_pppoe_parser, mock_interface_parser);
+        let runner = PPPoERunner::new(op_command, mock_executor, mock_pppoe_parser, mock_interface_runner);
         let actual = runner.run().await.unwrap();
         assert_eq!(actual, vec![
             PPPoEClientSession {
@@ -1,13 +1,30 @@
-use clap::{crate_version, Parser};
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use anyhow::Context;
+use clap::{crate_version, ArgAction, Parser, ValueEnum};
 use derive_more::{AsRef, Deref, Display, From};
 
+use crate::service::COLLECTORS;
+
 #[derive(AsRef, Clone, Debug, Deref, Display, Eq, From, PartialEq)]
 #[as_ref(forward)]
 pub struct ViciPath(String);
 
+#[derive(Clone, Debug, Deref, Display, Eq, From, PartialEq)]
+pub struct ProcfsPath(String);
+
+#[derive(Clone, Debug, Deref, Display, Eq, From, PartialEq)]
+pub struct SysfsPath(String);
+
 #[derive(Clone, Debug, Deref, Display, Eq, From, PartialEq)]
 pub struct IpCommand(String);
 
+#[derive(Clone, Debug, Deref, Display, Eq, From, PartialEq)]
+pub struct IPsecCommand(String);
+
 #[derive(Clone, Debug, Deref, Display, Eq, From, PartialEq)]
 pub struct OpCommand(String);
 
@@ -17,6 +34,16 @@ pub struct OpDdnsCommand(String);
 #[derive(Clone, Debug, Deref, Display, Eq, From, PartialEq)]
 pub struct VtyshCommand(String);
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum BGPFormat {
+    /// Detect from EdgeOS version
+    Auto,
+    /// Plain text output of Quagga/ZebOS (IPv4 and IPv6 unicast in the default VRF only)
+    Text,
+    /// JSON output of FRR
+    Json,
+}
+
 #[derive(Debug, Eq, Parser, PartialEq)]
 #[command(version = version())]
 pub struct Config {
@@ -40,10 +67,22 @@ pub struct Config {
     #[arg(long, env, default_value_t = default_vici_path())]
     pub vici_path: ViciPath,
 
+    /// Path to procfs
+    #[arg(long, env, default_value_t = default_procfs_path())]
+    pub procfs_path: ProcfsPath,
+
+    /// Path to sysfs
+    #[arg(long, env, default_value_t = default_sysfs_path())]
+    pub sysfs_path: SysfsPath,
+
     /// Path to ip command
     #[arg(long, env, default_value_t = default_ip_command())]
     pub ip_command: IpCommand,
 
+    /// Path to ipsec command
+    #[arg(long, env, default_value_t = default_ipsec_command())]
+    pub ipsec_command: IPsecCommand,
+
     /// Path to op command
     #[arg(long, env, default_value_t = default_op_command())]
     pub op_command: OpCommand,
@@ -55,6 +94,86 @@ pub struct Config {
     /// Path to vtysh command
     #[arg(long, env, default_value_t = default_vtysh_command())]
     pub vtysh_command: VtyshCommand,
+
+    /// Output format of vtysh for BGP
+    #[arg(long, env, value_enum, default_value_t = BGPFormat::Auto)]
+    pub bgp_format: BGPFormat,
+
+    /// Address of DNS resolver to verify DDNS records (if not specified, records are not verified)
+    #[arg(long, env, value_parser = parse_resolver)]
+    pub ddns_resolver: Option<SocketAddr>,
+
+    /// Timeout in seconds for each command (if not specified, commands never time out)
+    #[arg(long, env, value_parser = parse_seconds)]
+    pub command_timeout: Option<Duration>,
+
+    /// Timeout in seconds for each collector (if not specified, collectors never time out)
+    #[arg(long, env, value_parser = parse_seconds)]
+    pub collector_timeout: Option<Duration>,
+
+    /// Interval in seconds to poll collectors in background (if not specified, collectors run on each scrape)
+    #[arg(long, env, value_parser = parse_seconds)]
+    pub poll_interval: Option<Duration>,
+
+    /// Interval in seconds to poll a specific collector in background (e.g. ddns=300)
+    #[arg(long = "collector-poll-interval", env = "COLLECTOR_POLL_INTERVALS", value_delimiter = ',', value_parser = parse_collector_seconds)]
+    pub collector_poll_intervals: Vec<(String, Duration)>,
+
+    /// Enable BGP collector
+    #[arg(long = "collector.bgp", env = "COLLECTOR_BGP", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_bgp: bool,
+
+    /// Enable conntrack collector
+    #[arg(long = "collector.conntrack", env = "COLLECTOR_CONNTRACK", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_conntrack: bool,
+
+    /// Enable breakdown of conntrack entries by protocol and state
+    #[arg(long = "collector.conntrack.entries", env = "COLLECTOR_CONNTRACK_ENTRIES", default_value_t = false, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_conntrack_entries: bool,
+
+    /// Enable DDNS collector
+    #[arg(long = "collector.ddns", env = "COLLECTOR_DDNS", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_ddns: bool,
+
+    /// Enable DHCP collector
+    #[arg(long = "collector.dhcp", env = "COLLECTOR_DHCP", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_dhcp: bool,
+
+    /// Enable per-lease info of DHCP collector
+    #[arg(long = "collector.dhcp.leases", env = "COLLECTOR_DHCP_LEASES", default_value_t = false, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_dhcp_leases: bool,
+
+    /// Enable firewall collector
+    #[arg(long = "collector.firewall", env = "COLLECTOR_FIREWALL", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_firewall: bool,
+
+    /// Enable interface collector
+    #[arg(long = "collector.interface", env = "COLLECTOR_INTERFACE", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_interface: bool,
+
+    /// Enable IPsec collector
+    #[arg(long = "collector.ipsec", env = "COLLECTOR_IPSEC", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_ipsec: bool,
+
+    /// Enable load balance collector
+    #[arg(long = "collector.load_balance", env = "COLLECTOR_LOAD_BALANCE", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_load_balance: bool,
+
+    /// Enable OSPF collector
+    #[arg(long = "collector.ospf", env = "COLLECTOR_OSPF", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_ospf: bool,
+
+    /// Enable PPPoE collector
+    #[arg(long = "collector.pppoe", env = "COLLECTOR_PPPOE", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_pppoe: bool,
+
+    /// Enable system collector
+    #[arg(long = "collector.system", env = "COLLECTOR_SYSTEM", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_system: bool,
+
+    /// Enable version collector
+    #[arg(long = "collector.version", env = "COLLECTOR_VERSION", default_value_t = true, action = ArgAction::Set, num_args = 0..=1, default_missing_value = "true")]
+    pub collector_version: bool,
 }
 
 pub fn init() -> Config {
@@ -77,10 +196,22 @@ fn default_vici_path() -> ViciPath {
     ViciPath("/run/charon.vici".to_string())
 }
 
+fn default_procfs_path() -> ProcfsPath {
+    ProcfsPath("/proc".to_string())
+}
+
+fn default_sysfs_path() -> SysfsPath {
+    SysfsPath("/sys".to_string())
+}
+
 fn default_ip_command() -> IpCommand {
     IpCommand("/bin/ip".to_string())
 }
 
+fn default_ipsec_command() -> IPsecCommand {
+    IPsecCommand("/usr/sbin/ipsec".to_string())
+}
+
 fn default_op_command() -> OpCommand {
     OpCommand("/opt/vyatta/bin/vyatta-op-cmd-wrapper".to_string())
 }
@@ -93,7 +224,39 @@ fn default_vtysh_command() -> VtyshCommand {
     VtyshCommand("/opt/vyatta/sbin/ubnt_vtysh".to_string())
 }
 
+fn parse_seconds(s: &str) -> anyhow::Result<Duration> {
+    let seconds = s.parse()?;
+    let duration = Duration::try_from_secs_f64(seconds)?;
+    anyhow::ensure!(!duration.is_zero(), "must be greater than zero");
+    Ok(duration)
+}
+
+fn parse_collector_seconds(s: &str) -> anyhow::Result<(String, Duration)> {
+    let (collector, seconds) = s.split_once('=').context("expected <collector>=<seconds>")?;
+    anyhow::ensure!(COLLECTORS.contains(&collector), "unknown collector: {collector}");
+    let duration = parse_seconds(seconds)?;
+    Ok((collector.to_string(), duration))
+}
+
+fn parse_resolver(s: &str) -> anyhow::Result<SocketAddr> {
+    // Port number can be omitted as in resolv.conf.
+    let addr = match s.parse::<IpAddr>() {
+        Ok(ip) => SocketAddr::new(ip, 53),
+        Err(_) => s.parse()?,
+    };
+    Ok(addr)
+}
+
 impl Config {
+    pub fn poll_interval(&self, collector: &str) -> Option<Duration> {
+        self.collector_poll_intervals
+            .iter()
+            .rev()
+            .find(|(c, _)| c == collector)
+            .map(|&(_, duration)| duration)
+            .or(self.poll_interval)
+    }
+
     fn init(&self) {
         env_logger::builder()
             .format_target(false)
@@ -103,3 +266,50 @@ impl Config {
             .init();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::error::ErrorKind;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn seconds() {
+        let config = Config::try_parse_from([
+            "edgerouter-exporter",
+            "--port", "9100",
+            "--command-timeout", "1.5",
+            "--poll-interval", "30",
+            "--collector-poll-interval", "ddns=300",
+        ]).unwrap();
+
+        assert_eq!(config.command_timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(config.poll_interval("bgp"), Some(Duration::from_secs(30)));
+        assert_eq!(config.poll_interval("ddns"), Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn zero_seconds() {
+        for args in [
+            ["--command-timeout", "0"],
+            ["--collector-timeout", "0"],
+            ["--poll-interval", "0.0"],
+            ["--collector-poll-interval", "ddns=0"],
+        ] {
+            let e = Config::try_parse_from(["edgerouter-exporter", "--port", "9100"].into_iter().chain(args)).unwrap_err();
+            assert_eq!(e.kind(), ErrorKind::ValueValidation);
+        }
+    }
+
+    #[test]
+    fn unknown_collector() {
+        let e = Config::try_parse_from([
+            "edgerouter-exporter",
+            "--port", "9100",
+            "--collector-poll-interval", "dns=300",
+        ]).unwrap_err();
+
+        assert_eq!(e.kind(), ErrorKind::ValueValidation);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod conntrack;
+pub mod conntrack_stat;
+pub mod load_average;
+pub mod meminfo;
+pub mod net_dev;
+pub mod stat;
+pub mod uptime;
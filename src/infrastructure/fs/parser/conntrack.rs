@@ -0,0 +1,109 @@
+use anyhow::Context;
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{alphanumeric1, newline, not_line_ending, space1, u64},
+    combinator::{map, opt, verify},
+    error::Error,
+    multi::many0,
+    sequence::{preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::conntrack::ConntrackEntry,
+    infrastructure::cmd::parser::Parser,
+    service::conntrack::ConntrackEntryResult,
+};
+
+pub struct ConntrackParser;
+
+impl Parser for ConntrackParser {
+    type Context<'a> = ();
+    type Item = ConntrackEntryResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_conntrack(input)
+            .finish()
+            .map(|(_, entries)| entries)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse conntrack entries")
+    }
+}
+
+fn parse_conntrack(input: &str) -> IResult<&str, ConntrackEntryResult> {
+    many0(
+        map(
+            terminated(
+                (
+                    preceded((alphanumeric1, space1, u64, space1), map(alphanumeric1, &str::to_string)),
+                    preceded((space1, u64, space1, u64), opt(
+                        preceded(
+                            space1,
+                            map(verify(take_till1(|c: char| c.is_ascii_whitespace()), |s: &str| !s.contains('=')), &str::to_string),
+                        ),
+                    )),
+                ),
+                (not_line_ending, newline),
+            ),
+            |(protocol, state)| {
+                ConntrackEntry {
+                    protocol,
+                    state,
+                }
+            },
+        ),
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = ConntrackParser;
+        let input = "";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![]);
+    }
+
+    #[test]
+    fn conntrack() {
+        let parser = ConntrackParser;
+        let input = indoc! {"
+            ipv4     2 tcp      6 431999 ESTABLISHED src=192.168.1.10 dst=203.0.113.1 sport=50000 dport=443 src=203.0.113.1 dst=198.51.100.1 sport=443 dport=50000 [ASSURED] mark=0 use=1
+            ipv4     2 tcp      6 119 TIME_WAIT src=192.168.1.11 dst=203.0.113.2 sport=50001 dport=80 src=203.0.113.2 dst=198.51.100.1 sport=80 dport=50001 [ASSURED] mark=0 use=1
+            ipv4     2 udp      17 29 src=192.168.1.10 dst=192.0.2.53 sport=53000 dport=53 src=192.0.2.53 dst=198.51.100.1 sport=53 dport=53000 mark=0 use=1
+            ipv6     10 icmpv6   58 29 src=2001:db8::1 dst=2001:db8::2 type=128 code=0 id=1 src=2001:db8::2 dst=2001:db8::1 type=129 code=0 id=1 mark=0 use=1
+            ipv4     2 unknown  47 599 src=192.0.2.1 dst=192.0.2.2 src=192.0.2.2 dst=192.0.2.1 mark=0 use=1
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            ConntrackEntry {
+                protocol: "tcp".to_string(),
+                state: Some("ESTABLISHED".to_string()),
+            },
+            ConntrackEntry {
+                protocol: "tcp".to_string(),
+                state: Some("TIME_WAIT".to_string()),
+            },
+            ConntrackEntry {
+                protocol: "udp".to_string(),
+                state: None,
+            },
+            ConntrackEntry {
+                protocol: "icmpv6".to_string(),
+                state: None,
+            },
+            ConntrackEntry {
+                protocol: "unknown".to_string(),
+                state: None,
+            },
+        ]);
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::Context;
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{hex_digit1, newline, space0},
+    combinator::{map_res, verify},
+    error::Error,
+    multi::many1,
+    sequence::{preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::conntrack::ConntrackStatistics,
+    infrastructure::cmd::parser::Parser,
+    service::conntrack::ConntrackStatisticsResult,
+};
+
+pub struct ConntrackStatParser;
+
+impl Parser for ConntrackStatParser {
+    type Context<'a> = ();
+    type Item = ConntrackStatisticsResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_conntrack_stat(input)
+            .finish()
+            .map(|(_, statistics)| statistics)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse conntrack statistics")
+    }
+}
+
+fn parse_conntrack_stat(input: &str) -> IResult<&str, ConntrackStatisticsResult> {
+    let (input, columns) = terminated(
+        many1(preceded(space0, take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'))),
+        (space0, newline),
+    ).parse_complete(input)?;
+
+    let width = columns.len();
+    let column = |name| columns.iter().position(|c| *c == name);
+    let (insert_failed, drop, early_drop, search_restart) = (
+        column("insert_failed"),
+        column("drop"),
+        column("early_drop"),
+        column("search_restart"),
+    );
+
+    map_res(
+        many1(
+            terminated(
+                verify(
+                    many1(preceded(space0, map_res(hex_digit1, |s| u64::from_str_radix(s, 16)))),
+                    move |values: &Vec<u64>| values.len() == width,
+                ),
+                (space0, newline),
+            ),
+        ),
+        move |rows| {
+            rows.into_iter()
+                .enumerate()
+                .map(|(cpu, values)| {
+                    let value = |index: Option<usize>| index.map(|i| values[i]);
+                    Ok::<_, &str>(ConntrackStatistics {
+                        cpu,
+                        insert_failed: value(insert_failed).ok_or("insert_failed")?,
+                        drop: value(drop).ok_or("drop")?,
+                        early_drop: value(early_drop).ok_or("early_drop")?,
+                        search_restart: value(search_restart),
+                    })
+                })
+                .collect()
+        },
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = ConntrackStatParser;
+        let input = "";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn conntrack_stat() {
+        let parser = ConntrackStatParser;
+        let input = indoc! {"
+            entries  clashres found new invalid ignore delete chainlength insert insert_failed drop early_drop icmp_error  expect_new expect_create expect_delete search_restart
+            000000b4  00000000 00000000 00000000 00000002 00000017 00000000 00000000 00000000 00000001 00000002 00000003 00000000  00000000 00000000 00000000 0000000a
+            000000b4  00000000 00000000 00000000 00000000 00000009 00000000 00000000 00000000 00000000 00000010 00000000 00000000  00000000 00000000 00000000 00000000
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            ConntrackStatistics {
+                cpu: 0,
+                insert_failed: 1,
+                drop: 2,
+                early_drop: 3,
+                search_restart: Some(10),
+            },
+            ConntrackStatistics {
+                cpu: 1,
+                insert_failed: 0,
+                drop: 16,
+                early_drop: 0,
+                search_restart: Some(0),
+            },
+        ]);
+    }
+
+    #[test]
+    fn conntrack_stat_without_search_restart() {
+        let parser = ConntrackStatParser;
+        let input = indoc! {"
+            entries  searched found new invalid ignore delete delete_list insert insert_failed drop early_drop icmp_error  expect_new expect_create expect_delete
+            00000040  00000123 00000456 00000789 00000000 00000001 00000002 00000003 00000004 00000005 00000006 00000007 00000000  00000000 00000000 00000000
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            ConntrackStatistics {
+                cpu: 0,
+                insert_failed: 5,
+                drop: 6,
+                early_drop: 7,
+                search_restart: None,
+            },
+        ]);
+    }
+}
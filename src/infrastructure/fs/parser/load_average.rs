@@ -0,0 +1,78 @@
+use anyhow::Context;
+use nom::{
+    character::complete::{newline, not_line_ending, space1},
+    combinator::map,
+    error::Error,
+    number::complete::double,
+    sequence::{preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::system::LoadAverage,
+    infrastructure::cmd::parser::Parser,
+    service::system::LoadAverageResult,
+};
+
+pub struct LoadAverageParser;
+
+impl Parser for LoadAverageParser {
+    type Context<'a> = ();
+    type Item = LoadAverageResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_load_average(input)
+            .finish()
+            .map(|(_, load_average)| load_average)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse load average")
+    }
+}
+
+fn parse_load_average(input: &str) -> IResult<&str, LoadAverageResult> {
+    map(
+        terminated(
+            (
+                double,
+                preceded(space1, double),
+                preceded(space1, double),
+            ),
+            (not_line_ending, newline),
+        ),
+        |(one, five, fifteen)| {
+            LoadAverage {
+                one,
+                five,
+                fifteen,
+            }
+        },
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = LoadAverageParser;
+        let input = "";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn load_average() {
+        let parser = LoadAverageParser;
+        let input = "0.12 0.34 0.56 1/123 4567\n";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, LoadAverage {
+            one: 0.12,
+            five: 0.34,
+            fifteen: 0.56,
+        });
+    }
+}
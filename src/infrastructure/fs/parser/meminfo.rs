@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, newline, space0, u64},
+    combinator::{map, map_res, opt},
+    error::Error,
+    multi::many0,
+    sequence::{delimited, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::system::Memory,
+    infrastructure::cmd::parser::Parser,
+    service::system::MemoryResult,
+};
+
+pub struct MeminfoParser;
+
+impl Parser for MeminfoParser {
+    type Context<'a> = ();
+    type Item = MemoryResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_meminfo(input)
+            .finish()
+            .map(|(_, memory)| memory)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse memory information")
+    }
+}
+
+fn parse_meminfo(input: &str) -> IResult<&str, MemoryResult> {
+    map_res(
+        many0(
+            map(
+                terminated(
+                    (
+                        terminated(take_till1(|c| c == ':' || c == '\n'), char(':')),
+                        delimited(space0, u64, space0),
+                        opt(tag("kB")),
+                    ),
+                    newline,
+                ),
+                |(key, value, unit)| (key, if unit.is_some() { value * 1024 } else { value }),
+            ),
+        ),
+        |entries| {
+            let entries: HashMap<_, _> = entries.into_iter().collect();
+            let field = |key| entries.get(key).copied().ok_or(key);
+            Ok::<_, &str>(Memory {
+                total: field("MemTotal")?,
+                free: field("MemFree")?,
+                available: field("MemAvailable").ok(),
+                buffers: field("Buffers")?,
+                cached: field("Cached")?,
+                swap_total: field("SwapTotal")?,
+                swap_free: field("SwapFree")?,
+            })
+        },
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = MeminfoParser;
+        let input = "";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn meminfo() {
+        let parser = MeminfoParser;
+        let input = indoc! {"
+            MemTotal:         511948 kB
+            MemFree:          312420 kB
+            MemAvailable:     401236 kB
+            Buffers:           10240 kB
+            Cached:            98304 kB
+            SwapCached:            0 kB
+            SwapTotal:             0 kB
+            SwapFree:              0 kB
+            HugePages_Total:       0
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Memory {
+            total: 524234752,
+            free: 319918080,
+            available: Some(410865664),
+            buffers: 10485760,
+            cached: 100663296,
+            swap_total: 0,
+            swap_free: 0,
+        });
+    }
+
+    #[test]
+    fn meminfo_without_available() {
+        let parser = MeminfoParser;
+        let input = indoc! {"
+            MemTotal:         255488 kB
+            MemFree:          120832 kB
+            Buffers:            8192 kB
+            Cached:            65536 kB
+            SwapTotal:         65536 kB
+            SwapFree:          32768 kB
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Memory {
+            total: 261619712,
+            free: 123731968,
+            available: None,
+            buffers: 8388608,
+            cached: 67108864,
+            swap_total: 67108864,
+            swap_free: 33554432,
+        });
+    }
+}
@@ -0,0 +1,147 @@
+use anyhow::Context;
+use nom::{
+    bytes::complete::take_till,
+    character::complete::{char, newline, not_line_ending, space0, u64},
+    combinator::map,
+    error::Error,
+    multi::{count, many0},
+    sequence::{delimited, preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::interface::InterfaceCounters,
+    infrastructure::cmd::parser::Parser,
+    service::interface::InterfaceCountersResult,
+};
+
+pub struct NetDevParser;
+
+impl Parser for NetDevParser {
+    type Context<'a> = ();
+    type Item = InterfaceCountersResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_net_dev(input)
+            .finish()
+            .map(|(_, counters)| counters)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse network device statistics")
+    }
+}
+
+fn parse_net_dev(input: &str) -> IResult<&str, InterfaceCountersResult> {
+    preceded(
+        count(terminated(not_line_ending, newline), 2),
+        many0(
+            map(
+                (
+                    delimited(
+                        space0,
+                        map(take_till(|c| c == ':' || c == '\n'), |s: &str| s.trim().to_string()),
+                        char(':'),
+                    ),
+                    terminated(
+                        count(preceded(space0, u64), 16),
+                        (space0, newline),
+                    ),
+                ),
+                |(ifname, values)| {
+                    InterfaceCounters {
+                        ifname,
+                        receive_bytes: values[0],
+                        receive_packets: values[1],
+                        receive_errors: values[2],
+                        receive_dropped: values[3],
+                        receive_multicast: values[7],
+                        transmit_bytes: values[8],
+                        transmit_packets: values[9],
+                        transmit_errors: values[10],
+                        transmit_dropped: values[11],
+                    }
+                },
+            ),
+        ),
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = NetDevParser;
+        let input = "";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn net_dev() {
+        let parser = NetDevParser;
+        let input = indoc! {"
+            Inter-|   Receive                                                |  Transmit
+             face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+                lo:    1000      10    0    0    0     0          0         0     1000      10    0    0    0     0       0          0
+              eth0: 123456789  987654    1    2    0     0          0       300 987654321  123456    3    4    0     0       0          0
+            switch0:     500       5    0    0    0     0          0         1      600       6    0    0    0     0       0          0
+            pppoe0: 2000000    3000    0    7    0     0          0         0  4000000    5000    0    8    0     0       0          0
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            InterfaceCounters {
+                ifname: "lo".to_string(),
+                receive_bytes: 1000,
+                receive_packets: 10,
+                receive_errors: 0,
+                receive_dropped: 0,
+                receive_multicast: 0,
+                transmit_bytes: 1000,
+                transmit_packets: 10,
+                transmit_errors: 0,
+                transmit_dropped: 0,
+            },
+            InterfaceCounters {
+                ifname: "eth0".to_string(),
+                receive_bytes: 123456789,
+                receive_packets: 987654,
+                receive_errors: 1,
+                receive_dropped: 2,
+                receive_multicast: 300,
+                transmit_bytes: 987654321,
+                transmit_packets: 123456,
+                transmit_errors: 3,
+                transmit_dropped: 4,
+            },
+            InterfaceCounters {
+                ifname: "switch0".to_string(),
+                receive_bytes: 500,
+                receive_packets: 5,
+                receive_errors: 0,
+                receive_dropped: 0,
+                receive_multicast: 1,
+                transmit_bytes: 600,
+                transmit_packets: 6,
+                transmit_errors: 0,
+                transmit_dropped: 0,
+            },
+            InterfaceCounters {
+                ifname: "pppoe0".to_string(),
+                receive_bytes: 2000000,
+                receive_packets: 3000,
+                receive_errors: 0,
+                receive_dropped: 7,
+                receive_multicast: 0,
+                transmit_bytes: 4000000,
+                transmit_packets: 5000,
+                transmit_errors: 0,
+                transmit_dropped: 8,
+            },
+        ]);
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Context;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, newline, not_line_ending, space1, u64},
+    combinator::map,
+    error::Error,
+    multi::{count, many1},
+    sequence::{preceded, terminated},
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    domain::system::CpuTime,
+    infrastructure::cmd::parser::Parser,
+    service::system::CpuTimeResult,
+};
+
+pub struct StatParser;
+
+impl Parser for StatParser {
+    type Context<'a> = ();
+    type Item = CpuTimeResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_stat(input)
+            .finish()
+            .map(|(_, cpus)| cpus)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse kernel statistics")
+    }
+}
+
+fn parse_stat(input: &str) -> IResult<&str, CpuTimeResult> {
+    preceded(
+        (tag("cpu "), not_line_ending, newline),
+        many1(
+            map(
+                terminated(
+                    (
+                        preceded(tag("cpu"), map(digit1, &str::to_string)),
+                        count(preceded(space1, u64), 8),
+                    ),
+                    (not_line_ending, newline),
+                ),
+                |(cpu, values)| {
+                    CpuTime {
+                        cpu,
+                        user: values[0],
+                        nice: values[1],
+                        system: values[2],
+                        idle: values[3],
+                        iowait: values[4],
+                        irq: values[5],
+                        softirq: values[6],
+                        steal: values[7],
+                    }
+                },
+            ),
+        ),
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = StatParser;
+        let input = "";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn stat() {
+        let parser = StatParser;
+        let input = indoc! {"
+            cpu  3000 20 1500 80000 100 0 250 0 0 0
+            cpu0 1500 10 750 40000 60 0 200 0 0 0
+            cpu1 1500 10 750 40000 40 0 50 0 0 0
+            intr 123456 0 0 0
+            ctxt 654321
+            btime 1136214245
+            processes 1234
+            procs_running 1
+            procs_blocked 0
+            softirq 12345 0 1 2 3 4 5 6 7 8 9
+        "};
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, vec![
+            CpuTime {
+                cpu: "0".to_string(),
+                user: 1500,
+                nice: 10,
+                system: 750,
+                idle: 40000,
+                iowait: 60,
+                irq: 0,
+                softirq: 200,
+                steal: 0,
+            },
+            CpuTime {
+                cpu: "1".to_string(),
+                user: 1500,
+                nice: 10,
+                system: 750,
+                idle: 40000,
+                iowait: 40,
+                irq: 0,
+                softirq: 50,
+                steal: 0,
+            },
+        ]);
+    }
+}
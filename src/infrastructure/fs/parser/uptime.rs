@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use nom::{
+    character::complete::{newline, space1},
+    combinator::map_res,
+    error::Error,
+    number::complete::double,
+    sequence::terminated,
+    Finish, IResult, Parser as _,
+};
+
+use crate::{
+    infrastructure::cmd::parser::Parser,
+    service::system::UptimeResult,
+};
+
+pub struct UptimeParser;
+
+impl Parser for UptimeParser {
+    type Context<'a> = ();
+    type Item = UptimeResult;
+
+    fn parse(&self, input: &str, _context: ()) -> anyhow::Result<Self::Item> {
+        parse_uptime(input)
+            .finish()
+            .map(|(_, uptime)| uptime)
+            .map_err(|e| Error::new(e.input.to_string(), e.code))
+            .context("failed to parse uptime")
+    }
+}
+
+fn parse_uptime(input: &str) -> IResult<&str, UptimeResult> {
+    terminated(
+        map_res(double, Duration::try_from_secs_f64),
+        (space1, double, newline),
+    ).parse_complete(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let parser = UptimeParser;
+        let input = "";
+
+        assert!(parser.parse(input, ()).is_err());
+    }
+
+    #[test]
+    fn uptime() {
+        let parser = UptimeParser;
+        let input = "350735.47 234388.90\n";
+
+        let actual = parser.parse(input, ()).unwrap();
+        assert_eq!(actual, Duration::from_millis(350735470));
+    }
+}
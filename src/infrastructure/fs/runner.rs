@@ -0,0 +1,34 @@
+use std::{future::Future, path::Path};
+
+use anyhow::Context;
+use tokio::fs;
+
+pub mod conntrack;
+pub mod interface;
+pub mod system;
+
+#[cfg(test)]
+mockall::mock! {
+    pub(crate) Reader {}
+
+    impl Reader for Reader {
+        fn read(&self, path: &Path) -> impl Future<Output = anyhow::Result<String>> + Send;
+    }
+}
+
+pub trait Reader {
+    fn read(&self, path: &Path) -> impl Future<Output = anyhow::Result<String>> + Send;
+}
+
+#[derive(Clone, Copy)]
+pub struct FileReader;
+
+impl Reader for FileReader {
+    fn read(&self, path: &Path) -> impl Future<Output = anyhow::Result<String>> + Send {
+        log::debug!("reading {path:?}");
+
+        async move {
+            fs::read_to_string(path).await.context(format!("error reading {path:?}"))
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use std::path::Path;
+
+use anyhow::Context;
+use tokio::try_join;
+
+use crate::{
+    domain::conntrack::Conntrack,
+    infrastructure::{
+        cmd::parser::Parser,
+        config::env::ProcfsPath,
+        fs::runner::Reader,
+    },
+    service::{
+        conntrack::{ConntrackEntryResult, ConntrackResult, ConntrackStatisticsResult},
+        Runner,
+    },
+};
+
+pub struct ConntrackRunner<R, StatParser, EntryParser> {
+    procfs_path: ProcfsPath,
+    entries: bool,
+    reader: R,
+    stat_parser: StatParser,
+    entry_parser: EntryParser,
+}
+
+impl<R, StatParser, EntryParser> ConntrackRunner<R, StatParser, EntryParser>
+where
+    R: Reader + Send + Sync,
+    StatParser: Parser<Context<'static> = (), Item = ConntrackStatisticsResult> + Send + Sync,
+    EntryParser: Parser<Context<'static> = (), Item = ConntrackEntryResult> + Send + Sync,
+{
+    pub fn new(procfs_path: ProcfsPath, entries: bool, reader: R, stat_parser: StatParser, entry_parser: EntryParser) -> Self {
+        Self {
+            procfs_path,
+            entries,
+            reader,
+            stat_parser,
+            entry_parser,
+        }
+    }
+
+    async fn read(&self, name: &str) -> anyhow::Result<String> {
+        let path = Path::new(&*self.procfs_path).join(name);
+        self.reader.read(&path).await
+    }
+
+    async fn value(&self, name: &str) -> anyhow::Result<u64> {
+        let output = self.read(name).await?;
+        output.trim().parse().with_context(|| format!("failed to parse {name}"))
+    }
+
+    async fn statistics(&self) -> anyhow::Result<ConntrackStatisticsResult> {
+        let output = self.read("net/stat/nf_conntrack").await?;
+        let result = self.stat_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn entries(&self) -> anyhow::Result<Option<ConntrackEntryResult>> {
+        if !self.entries {
+            return Ok(None);
+        }
+
+        let output = self.read("net/nf_conntrack").await?;
+        let result = self.entry_parser.parse(&output, ())?;
+        Ok(Some(result))
+    }
+}
+
+impl<R, StatParser, EntryParser> Runner for ConntrackRunner<R, StatParser, EntryParser>
+where
+    R: Reader + Send + Sync,
+    StatParser: Parser<Context<'static> = (), Item = ConntrackStatisticsResult> + Send + Sync,
+    EntryParser: Parser<Context<'static> = (), Item = ConntrackEntryResult> + Send + Sync,
+{
+    type Item = ConntrackResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        let (count, max, statistics, entries) = try_join!(
+            self.value("sys/net/netfilter/nf_conntrack_count"),
+            self.value("sys/net/netfilter/nf_conntrack_max"),
+            self.statistics(),
+            self.entries(),
+        )?;
+
+        Ok(Conntrack {
+            count,
+            max,
+            statistics,
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use futures::future::ok;
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::conntrack::{ConntrackEntry, ConntrackStatistics},
+        infrastructure::fs::runner::MockReader,
+    };
+
+    use super::*;
+
+    mock! {
+        ConntrackStatParser {}
+
+        impl Parser for ConntrackStatParser {
+            type Context<'a> = ();
+            type Item = ConntrackStatisticsResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        ConntrackParser {}
+
+        impl Parser for ConntrackParser {
+            type Context<'a> = ();
+            type Item = ConntrackEntryResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    const STAT_OUTPUT: &str = "entries insert_failed drop early_drop search_restart\n00000002 00000001 00000002 00000003 00000004\n";
+
+    fn mock_reader() -> MockReader {
+        let mut mock_reader = MockReader::new();
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/sys/net/netfilter/nf_conntrack_count")))
+            .returning(|_| Box::pin(ok("2\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/sys/net/netfilter/nf_conntrack_max")))
+            .returning(|_| Box::pin(ok("16384\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/net/stat/nf_conntrack")))
+            .returning(|_| Box::pin(ok(STAT_OUTPUT.to_string())));
+        mock_reader
+    }
+
+    fn mock_stat_parser() -> MockConntrackStatParser {
+        let mut mock_stat_parser = MockConntrackStatParser::new();
+        mock_stat_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(STAT_OUTPUT), eq(()))
+            .returning(|_, _| Ok(vec![
+                ConntrackStatistics {
+                    cpu: 0,
+                    insert_failed: 1,
+                    drop: 2,
+                    early_drop: 3,
+                    search_restart: Some(4),
+                },
+            ]));
+        mock_stat_parser
+    }
+
+    #[tokio::test]
+    async fn conntrack() {
+        let procfs_path = ProcfsPath::from("/proc".to_string());
+        let entry_output = "ipv4     2 tcp      6 431999 ESTABLISHED src=192.0.2.1 dst=192.0.2.2 sport=50000 dport=443 mark=0 use=1\n";
+
+        let mut mock_reader = mock_reader();
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/net/nf_conntrack")))
+            .returning(|_| Box::pin(ok(entry_output.to_string())));
+
+        let mut mock_entry_parser = MockConntrackParser::new();
+        mock_entry_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(entry_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                ConntrackEntry {
+                    protocol: "tcp".to_string(),
+                    state: Some("ESTABLISHED".to_string()),
+                },
+            ]));
+
+        let runner = ConntrackRunner::new(procfs_path, true, mock_reader, mock_stat_parser(), mock_entry_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, Conntrack {
+            count: 2,
+            max: 16384,
+            statistics: vec![
+                ConntrackStatistics {
+                    cpu: 0,
+                    insert_failed: 1,
+                    drop: 2,
+                    early_drop: 3,
+                    search_restart: Some(4),
+                },
+            ],
+            entries: Some(vec![
+                ConntrackEntry {
+                    protocol: "tcp".to_string(),
+                    state: Some("ESTABLISHED".to_string()),
+                },
+            ]),
+        });
+    }
+
+    #[tokio::test]
+    async fn conntrack_without_entries() {
+        let procfs_path = ProcfsPath::from("/proc".to_string());
+
+        let mut mock_entry_parser = MockConntrackParser::new();
+        mock_entry_parser
+            .expect_parse()
+            .times(0);
+
+        let runner = ConntrackRunner::new(procfs_path, false, mock_reader(), mock_stat_parser(), mock_entry_parser);
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, Conntrack {
+            count: 2,
+            max: 16384,
+            statistics: vec![
+                ConntrackStatistics {
+                    cpu: 0,
+                    insert_failed: 1,
+                    drop: 2,
+                    early_drop: 3,
+                    search_restart: Some(4),
+                },
+            ],
+            entries: None,
+        });
+    }
+}
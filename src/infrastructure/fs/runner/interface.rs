@@ -0,0 +1,308 @@
+use std::{path::Path, str::FromStr};
+
+use futures::future::join_all;
+use tokio::try_join;
+
+use crate::{
+    domain::interface::{Interface, InterfaceCounters, InterfaceStatistics},
+    infrastructure::{
+        cmd::parser::Parser,
+        config::env::{ProcfsPath, SysfsPath},
+        fs::runner::Reader,
+    },
+    service::{
+        interface::{InterfaceCountersResult, InterfaceResult, InterfaceStatisticsResult},
+        Runner,
+    },
+};
+
+pub struct InterfaceStatisticsRunner<I, R, P> {
+    procfs_path: ProcfsPath,
+    sysfs_path: SysfsPath,
+    interface_runner: I,
+    reader: R,
+    parser: P,
+}
+
+impl<I, R, P> InterfaceStatisticsRunner<I, R, P>
+where
+    I: Runner<Item = InterfaceResult> + Send + Sync,
+    R: Reader + Send + Sync,
+    P: Parser<Context<'static> = (), Item = InterfaceCountersResult> + Send + Sync,
+{
+    pub fn new(
+        procfs_path: ProcfsPath,
+        sysfs_path: SysfsPath,
+        interface_runner: I,
+        reader: R,
+        parser: P,
+    ) -> Self {
+        Self {
+            procfs_path,
+            sysfs_path,
+            interface_runner,
+            reader,
+            parser,
+        }
+    }
+
+    async fn counters(&self) -> anyhow::Result<InterfaceCountersResult> {
+        let path = Path::new(&*self.procfs_path).join("net/dev");
+        let output = self.reader.read(&path).await?;
+        let result = self.parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn attribute<T>(&self, ifname: &str, name: &str) -> Option<T>
+    where
+        T: FromStr,
+    {
+        let path = Path::new(&*self.sysfs_path).join("class/net").join(ifname).join(name);
+        match self.reader.read(&path).await {
+            Ok(value) => value.trim().parse().ok(),
+            Err(e) => {
+                log::debug!("{e:?}");
+                None
+            },
+        }
+    }
+
+    async fn statistics(&self, interfaces: &[Interface], counters: InterfaceCounters) -> InterfaceStatistics {
+        let interface = interfaces
+            .iter()
+            .find(|i| i.ifname.split('@').next() == Some(&counters.ifname))
+            .cloned();
+
+        let interface = match interface {
+            Some(interface) => interface,
+            None => Interface {
+                ifname: counters.ifname.clone(),
+                operstate: self.attribute::<String>(&counters.ifname, "operstate").await
+                    .map(|s| s.to_uppercase())
+                    .unwrap_or_else(|| "UNKNOWN".to_string()),
+                addr_info: vec![],
+            },
+        };
+
+        InterfaceStatistics {
+            interface,
+            mtu: self.attribute(&counters.ifname, "mtu").await,
+            speed: self.attribute(&counters.ifname, "speed").await,
+            carrier_changes: self.attribute(&counters.ifname, "carrier_changes").await,
+            counters,
+        }
+    }
+}
+
+impl<I, R, P> Runner for InterfaceStatisticsRunner<I, R, P>
+where
+    I: Runner<Item = InterfaceResult> + Send + Sync,
+    R: Reader + Send + Sync,
+    P: Parser<Context<'static> = (), Item = InterfaceCountersResult> + Send + Sync,
+{
+    type Item = InterfaceStatisticsResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        let (interfaces, counters) = try_join!(self.interface_runner.run(), self.counters())?;
+        let statistics = counters.into_iter().map(|c| self.statistics(&interfaces, c));
+        Ok(join_all(statistics).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        net::{IpAddr, Ipv4Addr},
+    };
+
+    use anyhow::anyhow;
+    use futures::future::{err, ok};
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{domain::interface::AddrInfo, infrastructure::fs::runner::MockReader};
+
+    use super::*;
+
+    mock! {
+        InterfaceRunner {}
+
+        impl Runner for InterfaceRunner {
+            type Item = InterfaceResult;
+
+            fn run(&self) -> impl Future<Output = anyhow::Result<<Self as Runner>::Item>> + Send;
+        }
+    }
+
+    mock! {
+        NetDevParser {}
+
+        impl Parser for NetDevParser {
+            type Context<'a> = ();
+            type Item = InterfaceCountersResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    #[tokio::test]
+    async fn statistics() {
+        let procfs_path = ProcfsPath::from("/proc".to_string());
+        let sysfs_path = SysfsPath::from("/sys".to_string());
+        let net_dev_output = "net_dev";
+
+        let mut mock_reader = MockReader::new();
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/proc/net/dev"))
+            .returning(|_| Box::pin(ok(net_dev_output.to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/eth0/mtu"))
+            .returning(|_| Box::pin(ok("1500\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/eth0/speed"))
+            .returning(|_| Box::pin(ok("1000\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/eth0/carrier_changes"))
+            .returning(|_| Box::pin(ok("3\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/pppoe0/operstate"))
+            .returning(|_| Box::pin(ok("unknown\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/pppoe0/mtu"))
+            .returning(|_| Box::pin(ok("1492\n".to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/pppoe0/speed"))
+            .returning(|_| Box::pin(err(anyhow!("Invalid argument (os error 22)"))));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .withf(|path| path == Path::new("/sys/class/net/pppoe0/carrier_changes"))
+            .returning(|_| Box::pin(ok("0\n".to_string())));
+
+        let mut mock_interface_runner = MockInterfaceRunner::new();
+        mock_interface_runner
+            .expect_run()
+            .times(1)
+            .returning(|| Box::pin(ok(vec![
+                Interface {
+                    ifname: "eth0".to_string(),
+                    operstate: "UP".to_string(),
+                    addr_info: vec![
+                        AddrInfo {
+                            local: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+                            address: None,
+                            prefixlen: 24,
+                        },
+                    ],
+                },
+            ])));
+
+        let mut mock_net_dev_parser = MockNetDevParser::new();
+        mock_net_dev_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(net_dev_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                InterfaceCounters {
+                    ifname: "eth0".to_string(),
+                    receive_bytes: 1000,
+                    receive_packets: 10,
+                    receive_errors: 1,
+                    receive_dropped: 2,
+                    receive_multicast: 3,
+                    transmit_bytes: 2000,
+                    transmit_packets: 20,
+                    transmit_errors: 4,
+                    transmit_dropped: 5,
+                },
+                InterfaceCounters {
+                    ifname: "pppoe0".to_string(),
+                    receive_bytes: 3000,
+                    receive_packets: 30,
+                    receive_errors: 0,
+                    receive_dropped: 0,
+                    receive_multicast: 0,
+                    transmit_bytes: 4000,
+                    transmit_packets: 40,
+                    transmit_errors: 0,
+                    transmit_dropped: 0,
+                },
+            ]));
+
+        let runner = InterfaceStatisticsRunner::new(
+            procfs_path,
+            sysfs_path,
+            mock_interface_runner,
+            mock_reader,
+            mock_net_dev_parser,
+        );
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, vec![
+            InterfaceStatistics {
+                interface: Interface {
+                    ifname: "eth0".to_string(),
+                    operstate: "UP".to_string(),
+                    addr_info: vec![
+                        AddrInfo {
+                            local: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)),
+                            address: None,
+                            prefixlen: 24,
+                        },
+                    ],
+                },
+                mtu: Some(1500),
+                speed: Some(1000),
+                carrier_changes: Some(3),
+                counters: InterfaceCounters {
+                    ifname: "eth0".to_string(),
+                    receive_bytes: 1000,
+                    receive_packets: 10,
+                    receive_errors: 1,
+                    receive_dropped: 2,
+                    receive_multicast: 3,
+                    transmit_bytes: 2000,
+                    transmit_packets: 20,
+                    transmit_errors: 4,
+                    transmit_dropped: 5,
+                },
+            },
+            InterfaceStatistics {
+                interface: Interface {
+                    ifname: "pppoe0".to_string(),
+                    operstate: "UNKNOWN".to_string(),
+                    addr_info: vec![],
+                },
+                mtu: Some(1492),
+                speed: None,
+                carrier_changes: Some(0),
+                counters: InterfaceCounters {
+                    ifname: "pppoe0".to_string(),
+                    receive_bytes: 3000,
+                    receive_packets: 30,
+                    receive_errors: 0,
+                    receive_dropped: 0,
+                    receive_multicast: 0,
+                    transmit_bytes: 4000,
+                    transmit_packets: 40,
+                    transmit_errors: 0,
+                    transmit_dropped: 0,
+                },
+            },
+        ]);
+    }
+}
@@ -0,0 +1,373 @@
+use std::path::Path;
+
+use tokio::try_join;
+
+use crate::{
+    domain::system::System,
+    infrastructure::{
+        cmd::parser::Parser,
+        config::env::ProcfsPath,
+        fs::runner::Reader,
+    },
+    service::{
+        system::{CpuTimeResult, HardwareResult, LoadAverageResult, MemoryResult, SystemResult, UptimeResult},
+        Runner,
+    },
+};
+
+pub struct SystemRunner<H, R, UptimeParser, LoadAverageParser, StatParser, MeminfoParser> {
+    procfs_path: ProcfsPath,
+    hardware_runner: H,
+    reader: R,
+    uptime_parser: UptimeParser,
+    load_average_parser: LoadAverageParser,
+    stat_parser: StatParser,
+    meminfo_parser: MeminfoParser,
+}
+
+impl<H, R, UptimeParser, LoadAverageParser, StatParser, MeminfoParser> SystemRunner<H, R, UptimeParser, LoadAverageParser, StatParser, MeminfoParser>
+where
+    H: Runner<Item = HardwareResult> + Send + Sync,
+    R: Reader + Send + Sync,
+    UptimeParser: Parser<Context<'static> = (), Item = UptimeResult> + Send + Sync,
+    LoadAverageParser: Parser<Context<'static> = (), Item = LoadAverageResult> + Send + Sync,
+    StatParser: Parser<Context<'static> = (), Item = CpuTimeResult> + Send + Sync,
+    MeminfoParser: Parser<Context<'static> = (), Item = MemoryResult> + Send + Sync,
+{
+    pub fn new(
+        procfs_path: ProcfsPath,
+        hardware_runner: H,
+        reader: R,
+        uptime_parser: UptimeParser,
+        load_average_parser: LoadAverageParser,
+        stat_parser: StatParser,
+        meminfo_parser: MeminfoParser,
+    ) -> Self {
+        Self {
+            procfs_path,
+            hardware_runner,
+            reader,
+            uptime_parser,
+            load_average_parser,
+            stat_parser,
+            meminfo_parser,
+        }
+    }
+
+    async fn read(&self, name: &str) -> anyhow::Result<String> {
+        let path = Path::new(&*self.procfs_path).join(name);
+        self.reader.read(&path).await
+    }
+
+    async fn uptime(&self) -> anyhow::Result<UptimeResult> {
+        let output = self.read("uptime").await?;
+        let result = self.uptime_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn load_average(&self) -> anyhow::Result<LoadAverageResult> {
+        let output = self.read("loadavg").await?;
+        let result = self.load_average_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn cpus(&self) -> anyhow::Result<CpuTimeResult> {
+        let output = self.read("stat").await?;
+        let result = self.stat_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn memory(&self) -> anyhow::Result<MemoryResult> {
+        let output = self.read("meminfo").await?;
+        let result = self.meminfo_parser.parse(&output, ())?;
+        Ok(result)
+    }
+
+    async fn hardware(&self) -> anyhow::Result<HardwareResult> {
+        match self.hardware_runner.run().await {
+            Ok(hardware) => Ok(hardware),
+            Err(e) => {
+                log::debug!("{e:?}");
+                Ok(HardwareResult::default())
+            },
+        }
+    }
+}
+
+impl<H, R, UptimeParser, LoadAverageParser, StatParser, MeminfoParser> Runner for SystemRunner<H, R, UptimeParser, LoadAverageParser, StatParser, MeminfoParser>
+where
+    H: Runner<Item = HardwareResult> + Send + Sync,
+    R: Reader + Send + Sync,
+    UptimeParser: Parser<Context<'static> = (), Item = UptimeResult> + Send + Sync,
+    LoadAverageParser: Parser<Context<'static> = (), Item = LoadAverageResult> + Send + Sync,
+    StatParser: Parser<Context<'static> = (), Item = CpuTimeResult> + Send + Sync,
+    MeminfoParser: Parser<Context<'static> = (), Item = MemoryResult> + Send + Sync,
+{
+    type Item = SystemResult;
+
+    async fn run(&self) -> anyhow::Result<Self::Item> {
+        let (uptime, load_average, cpus, memory, hardware) = try_join!(
+            self.uptime(),
+            self.load_average(),
+            self.cpus(),
+            self.memory(),
+            self.hardware(),
+        )?;
+
+        Ok(System {
+            uptime,
+            load_average,
+            cpus,
+            memory,
+            hardware,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::Future, path::PathBuf, time::Duration};
+
+    use anyhow::anyhow;
+    use futures::future::{err, ok};
+    use mockall::{mock, predicate::eq};
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        domain::system::{CpuTime, Hardware, LoadAverage, Memory, Sensor},
+        infrastructure::fs::runner::MockReader,
+    };
+
+    use super::*;
+
+    mock! {
+        HardwareRunner {}
+
+        impl Runner for HardwareRunner {
+            type Item = HardwareResult;
+
+            fn run(&self) -> impl Future<Output = anyhow::Result<<Self as Runner>::Item>> + Send;
+        }
+    }
+
+    mock! {
+        UptimeParser {}
+
+        impl Parser for UptimeParser {
+            type Context<'a> = ();
+            type Item = UptimeResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        LoadAverageParser {}
+
+        impl Parser for LoadAverageParser {
+            type Context<'a> = ();
+            type Item = LoadAverageResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        StatParser {}
+
+        impl Parser for StatParser {
+            type Context<'a> = ();
+            type Item = CpuTimeResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    mock! {
+        MeminfoParser {}
+
+        impl Parser for MeminfoParser {
+            type Context<'a> = ();
+            type Item = MemoryResult;
+
+            fn parse(&self, input: &str, context: <Self as Parser>::Context<'static>) -> anyhow::Result<<Self as Parser>::Item>;
+        }
+    }
+
+    fn mocks() -> (MockReader, MockUptimeParser, MockLoadAverageParser, MockStatParser, MockMeminfoParser) {
+        let uptime_output = "3723.45 7000.00\n";
+        let load_average_output = "0.12 0.34 0.56 1/123 4567\n";
+        let stat_output = "cpu  1500 10 750 40000 60 0 200 0 0 0\ncpu0 1500 10 750 40000 60 0 200 0 0 0\n";
+        let meminfo_output = "MemTotal: 511948 kB\n";
+
+        let mut mock_reader = MockReader::new();
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/uptime")))
+            .returning(|_| Box::pin(ok(uptime_output.to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/loadavg")))
+            .returning(|_| Box::pin(ok(load_average_output.to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/stat")))
+            .returning(|_| Box::pin(ok(stat_output.to_string())));
+        mock_reader
+            .expect_read()
+            .times(1)
+            .with(eq(PathBuf::from("/proc/meminfo")))
+            .returning(|_| Box::pin(ok(meminfo_output.to_string())));
+
+        let mut mock_uptime_parser = MockUptimeParser::new();
+        mock_uptime_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(uptime_output), eq(()))
+            .returning(|_, _| Ok(Duration::from_millis(3723450)));
+
+        let mut mock_load_average_parser = MockLoadAverageParser::new();
+        mock_load_average_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(load_average_output), eq(()))
+            .returning(|_, _| Ok(LoadAverage {
+                one: 0.12,
+                five: 0.34,
+                fifteen: 0.56,
+            }));
+
+        let mut mock_stat_parser = MockStatParser::new();
+        mock_stat_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(stat_output), eq(()))
+            .returning(|_, _| Ok(vec![
+                CpuTime {
+                    cpu: "0".to_string(),
+                    user: 1500,
+                    nice: 10,
+                    system: 750,
+                    idle: 40000,
+                    iowait: 60,
+                    irq: 0,
+                    softirq: 200,
+                    steal: 0,
+                },
+            ]));
+
+        let mut mock_meminfo_parser = MockMeminfoParser::new();
+        mock_meminfo_parser
+            .expect_parse()
+            .times(1)
+            .with(eq(meminfo_output), eq(()))
+            .returning(|_, _| Ok(Memory {
+                total: 524234752,
+                free: 319918080,
+                available: Some(410865664),
+                buffers: 10485760,
+                cached: 100663296,
+                swap_total: 0,
+                swap_free: 0,
+            }));
+
+        (mock_reader, mock_uptime_parser, mock_load_average_parser, mock_stat_parser, mock_meminfo_parser)
+    }
+
+    #[tokio::test]
+    async fn system() {
+        let procfs_path = ProcfsPath::from("/proc".to_string());
+        let (mock_reader, mock_uptime_parser, mock_load_average_parser, mock_stat_parser, mock_meminfo_parser) = mocks();
+
+        let mut mock_hardware_runner = MockHardwareRunner::new();
+        mock_hardware_runner
+            .expect_run()
+            .times(1)
+            .returning(|| Box::pin(ok(Hardware {
+                temperatures: vec![
+                    Sensor {
+                        name: "CPU Temp".to_string(),
+                        value: 56.0,
+                    },
+                ],
+                fans: vec![],
+            })));
+
+        let runner = SystemRunner::new(
+            procfs_path,
+            mock_hardware_runner,
+            mock_reader,
+            mock_uptime_parser,
+            mock_load_average_parser,
+            mock_stat_parser,
+            mock_meminfo_parser,
+        );
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual, System {
+            uptime: Duration::from_millis(3723450),
+            load_average: LoadAverage {
+                one: 0.12,
+                five: 0.34,
+                fifteen: 0.56,
+            },
+            cpus: vec![
+                CpuTime {
+                    cpu: "0".to_string(),
+                    user: 1500,
+                    nice: 10,
+                    system: 750,
+                    idle: 40000,
+                    iowait: 60,
+                    irq: 0,
+                    softirq: 200,
+                    steal: 0,
+                },
+            ],
+            memory: Memory {
+                total: 524234752,
+                free: 319918080,
+                available: Some(410865664),
+                buffers: 10485760,
+                cached: 100663296,
+                swap_total: 0,
+                swap_free: 0,
+            },
+            hardware: Hardware {
+                temperatures: vec![
+                    Sensor {
+                        name: "CPU Temp".to_string(),
+                        value: 56.0,
+                    },
+                ],
+                fans: vec![],
+            },
+        });
+    }
+
+    #[tokio::test]
+    async fn system_without_hardware() {
+        let procfs_path = ProcfsPath::from("/proc".to_string());
+        let (mock_reader, mock_uptime_parser, mock_load_average_parser, mock_stat_parser, mock_meminfo_parser) = mocks();
+
+        let mut mock_hardware_runner = MockHardwareRunner::new();
+        mock_hardware_runner
+            .expect_run()
+            .times(1)
+            .returning(|| Box::pin(err(anyhow!("Process exited with 1"))));
+
+        let runner = SystemRunner::new(
+            procfs_path,
+            mock_hardware_runner,
+            mock_reader,
+            mock_uptime_parser,
+            mock_load_average_parser,
+            mock_stat_parser,
+            mock_meminfo_parser,
+        );
+        let actual = runner.run().await.unwrap();
+        assert_eq!(actual.hardware, Hardware::default());
+    }
+}
@@ -1,13 +1,34 @@
 use std::future::Future;
 
 pub mod bgp;
+pub mod conntrack;
 pub mod ddns;
+pub mod dhcp;
+pub mod firewall;
 pub mod interface;
 pub mod ipsec;
 pub mod load_balance;
+pub mod ospf;
 pub mod pppoe;
+pub mod system;
 pub mod version;
 
+/// Names of the collectors as used in `--collector.<name>` and `collect[]`
+pub const COLLECTORS: &[&str] = &[
+    "bgp",
+    "conntrack",
+    "ddns",
+    "dhcp",
+    "firewall",
+    "interface",
+    "ipsec",
+    "load_balance",
+    "ospf",
+    "pppoe",
+    "system",
+    "version",
+];
+
 pub trait Runner {
     type Item;
 
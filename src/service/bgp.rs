@@ -1,3 +1,9 @@
-use crate::domain::bgp::BGPStatus;
+use crate::domain::bgp::{BGPNeighborDetail, BGPStatus, BGPTable};
 
 pub type BGPStatusResult = Option<BGPStatus>;
+
+pub type BGPTableResult = Vec<BGPTable>;
+
+pub type BGPNeighborDetailResult = Vec<BGPNeighborDetail>;
+
+pub type BGPVrfNeighborDetailResult = Vec<(String, BGPNeighborDetail)>;
@@ -0,0 +1,5 @@
+use crate::domain::conntrack::{Conntrack, ConntrackEntry, ConntrackStatistics};
+
+pub type ConntrackResult = Conntrack;
+pub type ConntrackStatisticsResult = Vec<ConntrackStatistics>;
+pub type ConntrackEntryResult = Vec<ConntrackEntry>;
@@ -1,3 +1,5 @@
-use crate::domain::ddns::DdnsStatus;
+use crate::domain::ddns::{DdnsRecord, DdnsStatus};
 
 pub type DdnsStatusResult = Vec<DdnsStatus>;
+
+pub type DdnsRecordResult = Vec<DdnsRecord>;
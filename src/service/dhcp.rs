@@ -0,0 +1,4 @@
+use crate::domain::dhcp::{DhcpLease, DhcpPool};
+
+pub type DhcpStatisticsResult = Vec<DhcpPool>;
+pub type DhcpLeaseResult = Vec<DhcpLease>;
@@ -0,0 +1,4 @@
+use crate::domain::firewall::{FirewallRuleset, NatRule};
+
+pub type FirewallResult = Vec<FirewallRuleset>;
+pub type NatResult = Vec<NatRule>;
@@ -1,3 +1,5 @@
-use crate::domain::interface::Interface;
+use crate::domain::interface::{Interface, InterfaceCounters, InterfaceStatistics};
 
 pub type InterfaceResult = Vec<Interface>;
+pub type InterfaceCountersResult = Vec<InterfaceCounters>;
+pub type InterfaceStatisticsResult = Vec<InterfaceStatistics>;
@@ -1,5 +1,15 @@
 use indexmap::IndexMap;
 
-use crate::domain::ipsec::SA;
+use crate::domain::ipsec::{Backend, Conn, SA, Stats, TunnelEvents};
 
 pub type IPsecResult = IndexMap<String, SA>;
+
+pub type IPsecConnResult = IndexMap<String, Conn>;
+
+pub type IPsecStatsResult = Option<Stats>;
+
+pub type IPsecCounterResult = IndexMap<String, u64>;
+
+pub type IPsecEventResult = Vec<TunnelEvents>;
+
+pub type IPsecBackendResult = Backend;
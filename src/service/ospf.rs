@@ -0,0 +1,7 @@
+use crate::domain::ospf::{OSPFArea, OSPFInterface, OSPFNeighbor};
+
+pub type OSPFAreaResult = Vec<OSPFArea>;
+
+pub type OSPFInterfaceResult = Vec<OSPFInterface>;
+
+pub type OSPFNeighborResult = Vec<OSPFNeighbor>;
@@ -0,0 +1,10 @@
+use std::time::Duration;
+
+use crate::domain::system::{CpuTime, Hardware, LoadAverage, Memory, System};
+
+pub type SystemResult = System;
+pub type UptimeResult = Duration;
+pub type LoadAverageResult = LoadAverage;
+pub type CpuTimeResult = Vec<CpuTime>;
+pub type MemoryResult = Memory;
+pub type HardwareResult = Hardware;